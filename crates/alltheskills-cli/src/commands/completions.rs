@@ -0,0 +1,36 @@
+//! Shell completion generation
+//!
+//! Renders completions for the `alltheskills` binary directly from its
+//! `clap::Command` definition, so they stay in sync as subcommands evolve.
+
+use clap::ValueEnum;
+use clap_complete::{generate, Shell};
+use clap_complete_nushell::Nushell;
+use std::io::Write;
+
+/// Shell to generate completions for
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum CompletionShell {
+    /// Bash
+    Bash,
+    /// Zsh
+    Zsh,
+    /// Fish
+    Fish,
+    /// PowerShell
+    PowerShell,
+    /// Nushell
+    Nushell,
+}
+
+/// Writes `shell` completions for `cmd` to `out`
+pub fn write_completions(cmd: &mut clap::Command, shell: CompletionShell, out: &mut dyn Write) {
+    let bin_name = cmd.get_name().to_string();
+    match shell {
+        CompletionShell::Bash => generate(Shell::Bash, cmd, bin_name, out),
+        CompletionShell::Zsh => generate(Shell::Zsh, cmd, bin_name, out),
+        CompletionShell::Fish => generate(Shell::Fish, cmd, bin_name, out),
+        CompletionShell::PowerShell => generate(Shell::PowerShell, cmd, bin_name, out),
+        CompletionShell::Nushell => generate(Nushell, cmd, bin_name, out),
+    }
+}
@@ -0,0 +1,87 @@
+use alltheskills::providers::{
+    ClaudeProvider, ClineProvider, CloudflareProvider, CodexProvider, CursorProvider, KiloProvider,
+    LocalProvider, MoltbotProvider, OpenClawProvider, RooProvider, VercelProvider,
+};
+use alltheskills::ConvertTarget;
+use alltheskills::{AllSkillsConfig, SkillProvider, SkillReader};
+use std::path::PathBuf;
+
+pub async fn convert_skill(name: &str, target_format: &str, output_dir: Option<&str>) -> Result<(), anyhow::Error> {
+    let config = AllSkillsConfig::default();
+    let mut reader = SkillReader::new(config);
+
+    reader.add_provider(ClaudeProvider);
+    reader.add_provider(ClineProvider);
+    reader.add_provider(CursorProvider);
+    reader.add_provider(RooProvider);
+    reader.add_provider(OpenClawProvider);
+    reader.add_provider(MoltbotProvider);
+    reader.add_provider(CodexProvider);
+    reader.add_provider(KiloProvider);
+    reader.add_provider(VercelProvider);
+    reader.add_provider(CloudflareProvider);
+    reader.add_provider(LocalProvider);
+
+    let name_lower = name.to_lowercase();
+    let skills = reader
+        .search_skills(|s| s.name.to_lowercase() == name_lower || s.id.to_lowercase() == name_lower)
+        .await?;
+
+    let Some(skill) = skills.into_iter().next() else {
+        anyhow::bail!("Skill '{}' not found. Try 'alltheskills list' to see available skills.", name);
+    };
+
+    let target = parse_convert_target(target_format)?;
+
+    // Re-read the content through the provider that produced this skill, since
+    // only it knows how to locate the skill's content file.
+    let provider: &dyn SkillProvider = match skill.source_type {
+        alltheskills::SourceType::Claude => &ClaudeProvider,
+        alltheskills::SourceType::Cline => &ClineProvider,
+        alltheskills::SourceType::Cursor => &CursorProvider,
+        alltheskills::SourceType::RooCode => &RooProvider,
+        alltheskills::SourceType::OpenClaw => &OpenClawProvider,
+        alltheskills::SourceType::Moltbot => &MoltbotProvider,
+        alltheskills::SourceType::OpenAICodex => &CodexProvider,
+        alltheskills::SourceType::KiloCode => &KiloProvider,
+        alltheskills::SourceType::Custom(ref name) if name == "vercel" => &VercelProvider,
+        alltheskills::SourceType::Custom(ref name) if name == "cloudflare" => &CloudflareProvider,
+        _ => &LocalProvider,
+    };
+    let content = provider.read_skill(&skill).await?;
+
+    let output = output_dir
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(".alltheskills/converted").join(&skill.id));
+
+    let warnings = alltheskills::convert_skill(&skill, &content, &target, &output)?;
+
+    println!(
+        "Converted '{}' ({:?} -> {target_format}) into {}",
+        skill.name, skill.format, output.display()
+    );
+    for warning in &warnings {
+        println!("⚠️  {warning}");
+    }
+
+    Ok(())
+}
+
+fn parse_convert_target(format: &str) -> Result<ConvertTarget, anyhow::Error> {
+    match format.to_lowercase().as_str() {
+        "claude" => Ok(ConvertTarget::Claude),
+        "cline" => Ok(ConvertTarget::Cline),
+        "cursor" => Ok(ConvertTarget::Cursor),
+        "roo" | "roocode" => Ok(ConvertTarget::Roo),
+        "openclaw" => Ok(ConvertTarget::OpenClaw),
+        "moltbot" => Ok(ConvertTarget::Moltbot),
+        "codex" => Ok(ConvertTarget::Codex),
+        "kilo" | "kilocode" => Ok(ConvertTarget::Kilo),
+        "vercel" => Ok(ConvertTarget::Vercel),
+        "cloudflare" => Ok(ConvertTarget::Cloudflare),
+        "markdown" | "md" => Ok(ConvertTarget::GenericMarkdown),
+        other => anyhow::bail!(
+            "Unsupported target format '{other}'. Supported: claude, cline, cursor, roo, openclaw, moltbot, codex, kilo, vercel, cloudflare, markdown"
+        ),
+    }
+}
@@ -22,7 +22,20 @@ pub async fn info_skill(name: &str) -> Result<(), anyhow::Error> {
 
     if skills.is_empty() {
         println!("Skill '{}' not found.", name);
-        println!("Try 'alltheskills list' to see available skills.");
+
+        let all_skills = reader.list_all_skills().await?;
+        let mut names: Vec<&str> = Vec::with_capacity(all_skills.len() * 2);
+        for s in &all_skills {
+            names.push(s.name.as_str());
+            names.push(s.id.as_str());
+        }
+
+        let suggestions = alltheskills::utils::suggest_closest(name, &names, 3);
+        if !suggestions.is_empty() {
+            println!("Did you mean: {}?", suggestions.join(", "));
+        } else {
+            println!("Try 'alltheskills list' to see available skills.");
+        }
     } else {
         let skill = &skills[0];
         println!("Skill: {}", skill.name);
@@ -1,54 +1,342 @@
 use alltheskills::SkillProvider;
+use alltheskills::core::{with_retry, RetryConfig};
+use alltheskills::dependencies::{CandidateVersions, DependencyResolver};
 use alltheskills::providers::github::GitHubProvider;
 use alltheskills::providers::local::LocalProvider;
-use alltheskills::types::SkillSource;
-use std::path::PathBuf;
+use alltheskills::providers::oci::OciProvider;
+use alltheskills::providers::register_builtin_providers;
+use alltheskills::types::{LockedSkill, SkillSource};
+use alltheskills::SkillReader;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
-pub async fn install_skill(source: &str, target: Option<&str>) -> Result<(), anyhow::Error> {
+pub async fn install_skill(
+    source: &str,
+    target: Option<&str>,
+    with_deps: bool,
+    capability: Option<&str>,
+    allow_wildcard: bool,
+) -> Result<(), anyhow::Error> {
     let target_path = target
         .map(PathBuf::from)
         .unwrap_or_else(|| PathBuf::from(".alltheskills"));
+    let config = crate::config::load_config()?;
+    let retry_config = RetryConfig::from_config(&config);
 
-    let skill_source = if source.starts_with("https://github.com/") {
-        // Parse GitHub URL
-        let url = source.trim_start_matches("https://github.com/");
+    let resolved = resolve_source(source, &config)?;
+    let skill_source = install_from_source(resolved, target_path.clone(), retry_config).await?;
+
+    enforce_and_grant(&skill_source, &target_path, capability, allow_wildcard)?;
+
+    println!("Successfully installed skill: {}", skill_source.name);
+
+    if with_deps {
+        install_dependencies(&skill_source, &config, &target_path, retry_config, allow_wildcard).await?;
+    }
+
+    Ok(())
+}
+
+/// Installs `resolved` into `target_path` via whichever provider handles
+/// its [`SkillSource`] variant, the same dispatch [`install_skill`] uses
+/// for the root skill -- factored out so [`install_dependencies`] can
+/// install each resolved dependency the same way.
+async fn install_from_source(
+    resolved: SkillSource,
+    target_path: PathBuf,
+    retry_config: RetryConfig,
+) -> Result<alltheskills::Skill, anyhow::Error> {
+    let skill = match &resolved {
+        SkillSource::GitHub { owner, repo, .. } => {
+            println!("Installing skill from GitHub: {}/{}", owner, repo);
+
+            let provider = GitHubProvider;
+            let skill = with_retry(retry_config, || provider.install(resolved.clone(), target_path.clone())).await?;
+            record_lock_entry(&skill)?;
+            skill
+        }
+        SkillSource::Local { path } => {
+            println!("Installing skill from local path: {}", path.display());
+
+            let provider = LocalProvider;
+            provider.install(resolved.clone(), target_path).await?
+        }
+        SkillSource::Oci { reference, .. } => {
+            println!("Installing skill from OCI registry: {}", reference);
+
+            // No `record_lock_entry` here -- `alltheskills.lock` pins a git
+            // commit (see `LockedSkill::commit`), and an OCI install is an
+            // unpacked tar layer with no `.git` directory to open. The
+            // tree's `hash_tree` digest is already recorded on
+            // `skill.metadata.content_hash` by `OciProvider::install`, so
+            // drift detection via `SkillProvider::verify` still works;
+            // there's just no git-style lock entry to write.
+            let provider = OciProvider;
+            with_retry(retry_config, || provider.install(resolved.clone(), target_path.clone())).await?
+        }
+        SkillSource::Remote { url, .. } => {
+            anyhow::bail!("No provider installs from a bare remote URL yet: {}", url);
+        }
+    };
+
+    Ok(skill)
+}
+
+/// Resolves `root`'s transitive dependency graph -- pinning to
+/// `skills.lock` when one is present and still satisfies `root`'s
+/// manifest, or else running [`DependencyResolver::resolve_transitive`]
+/// against every skill version known to the configured sources and
+/// (re-)writing the lockfile -- then installs each resolved dependency
+/// the same way as the root skill, into `base_target/<dependency-id>`
+///
+/// The resolver itself (semver matching, backtracking, conflict
+/// detection) already exists; this exposes it through
+/// `install --with-deps` and adds the `skills.lock` reproducibility layer
+/// on top.
+async fn install_dependencies(
+    root: &alltheskills::Skill,
+    config: &alltheskills::AllSkillsConfig,
+    base_target: &Path,
+    retry_config: RetryConfig,
+    allow_wildcard: bool,
+) -> Result<(), anyhow::Error> {
+    if root.metadata.dependencies.is_empty() {
+        return Ok(());
+    }
+
+    let mut reader = SkillReader::new(config.clone());
+    for (source, provider) in register_builtin_providers(&config.cache_dir).build_from_config(config) {
+        reader.add_provider_for_source(&source, provider);
+    }
+
+    let mut candidates: CandidateVersions = HashMap::new();
+    for skill in reader.list_all_skills().await? {
+        candidates.entry(skill.name.clone()).or_default().push(skill);
+    }
+
+    let resolver = DependencyResolver::new();
+    let lock_path = crate::deps_lock::lock_path(base_target);
+    let existing_lock = crate::deps_lock::load_lock(&lock_path)?;
+
+    let resolved = match existing_lock.as_ref().and_then(|lock| pin_from_lock(root, lock, &candidates)) {
+        Some(pinned) => {
+            println!("\nUsing skills.lock for {} dependenc{}", pinned.len(), if pinned.len() == 1 { "y" } else { "ies" });
+            pinned
+        }
+        None => {
+            let resolved = resolver.resolve_transitive(root, &candidates)?;
+            let fresh_lock = resolver.lock(root, &candidates)?;
+            crate::deps_lock::save_lock(&lock_path, &fresh_lock)?;
+            println!(
+                "\nResolved {} dependenc{} and wrote skills.lock:",
+                resolved.len(),
+                if resolved.len() == 1 { "y" } else { "ies" }
+            );
+            resolved
+        }
+    };
+
+    for dep_skill in resolved.values() {
+        let dep_target = base_target.join(&dep_skill.id);
+        let installed = install_from_source(dep_skill.source.clone(), dep_target.clone(), retry_config).await?;
+        enforce_and_grant(&installed, &dep_target, None, allow_wildcard)?;
+        println!("Successfully installed dependency: {}", installed.name);
+    }
+
+    Ok(())
+}
+
+/// Attempts to pin every one of `root`'s non-optional direct dependencies,
+/// and the transitive dependencies the lock records for them, to the
+/// exact versions recorded in `lock`, looking up each locked version's
+/// full [`alltheskills::Skill`] in `candidates` so it can actually be
+/// installed
+///
+/// Returns `None` -- forcing a fresh [`DependencyResolver::resolve_transitive`]
+/// -- if a dependency is missing from the lock, its locked version is no
+/// longer among the known candidates, or its manifest `version_req` no
+/// longer matches what's locked; the latter case prints a diff so the
+/// user knows why re-resolution happened.
+fn pin_from_lock(
+    root: &alltheskills::Skill,
+    lock: &alltheskills::types::DependencyLock,
+    candidates: &CandidateVersions,
+) -> Option<HashMap<String, alltheskills::Skill>> {
+    let mut resolved = HashMap::new();
+    let mut pending: Vec<String> = Vec::new();
+
+    for dep in &root.metadata.dependencies {
+        let Some(locked) = lock.skills.get(&dep.name) else {
+            if dep.optional {
+                continue;
+            }
+            println!("skills.lock has no entry for '{}'; re-resolving", dep.name);
+            return None;
+        };
+
+        if let Some(req) = &dep.version_req {
+            if !alltheskills::dependencies::version_matches(&locked.version, req) {
+                println!(
+                    "skills.lock pins '{}' at {}, but the manifest now requires {} -- re-resolving",
+                    dep.name, locked.version, req
+                );
+                return None;
+            }
+        }
+
+        let candidate = candidates
+            .get(&dep.name)?
+            .iter()
+            .find(|s| s.version.as_deref() == Some(locked.version.as_str()))?;
+        resolved.insert(dep.name.clone(), candidate.clone());
+        pending.extend(locked.dependencies.iter().cloned());
+    }
+
+    while let Some(name) = pending.pop() {
+        if name == root.name || resolved.contains_key(&name) {
+            continue;
+        }
+        let locked = lock.skills.get(&name)?;
+        let candidate = candidates
+            .get(&name)?
+            .iter()
+            .find(|s| s.version.as_deref() == Some(locked.version.as_str()))?;
+        resolved.insert(name, candidate.clone());
+        pending.extend(locked.dependencies.iter().cloned());
+    }
+
+    Some(resolved)
+}
+
+/// Resolves `source` into a concrete [`SkillSource`]: first checking
+/// `config.source_aliases` for an exact name match (e.g. `work-skills`
+/// configured to a GitHub owner/repo/subdir), then falling back to the
+/// existing `https://github.com/...` URL / local-path parsing.
+///
+/// A `source_aliases` entry maps a name directly to a terminal
+/// `SkillSource` value, never to another alias name, so an alias->alias
+/// cycle can't occur by construction -- unlike [`alltheskills::types::AliasValue`],
+/// which rewrites whole command lines and can reference another alias.
+fn resolve_source(source: &str, config: &alltheskills::AllSkillsConfig) -> Result<SkillSource, anyhow::Error> {
+    if let Some(aliased) = config.source_aliases.get(source) {
+        return Ok(aliased.clone());
+    }
+
+    if let Some(url) = source.strip_prefix("https://github.com/") {
         let parts: Vec<&str> = url.split('/').collect();
-        if parts.len() >= 2 {
-            let owner = parts[0].to_string();
-            let repo = parts[1].to_string();
-            let subdir = if parts.len() > 2 {
+        if parts.len() < 2 {
+            anyhow::bail!("Invalid GitHub URL: {}", source);
+        }
+
+        return Ok(SkillSource::GitHub {
+            owner: parts[0].to_string(),
+            repo: parts[1].to_string(),
+            subdir: if parts.len() > 2 {
                 Some(parts[2..].join("/"))
             } else {
                 None
-            };
+            },
+            branch: None,
+            version_req: None,
+            auth_token: None,
+            ssh: false,
+        });
+    }
 
-            println!("Installing skill from GitHub: {}/{}", owner, repo);
+    if let Some(reference) = source.strip_prefix("oci://") {
+        return Ok(SkillSource::Oci {
+            reference: reference.to_string(),
+            headers: Vec::new(),
+        });
+    }
 
-            let provider = GitHubProvider;
-            let source = SkillSource::GitHub {
-                owner,
-                repo,
-                subdir,
-                branch: None,
-            };
-
-            provider.install(source, target_path).await?
-        } else {
-            anyhow::bail!("Invalid GitHub URL: {}", source);
+    Ok(SkillSource::Local {
+        path: PathBuf::from(source).into(),
+    })
+}
+
+/// Surfaces the union of permissions `skill`'s manifest requests (plus
+/// `capability`'s, if one is attached at install time) and, unless any of
+/// them is wildcard-scoped, records them as granted -- so a later install
+/// of the same skill doesn't re-prompt unless its requested set grows.
+///
+/// This is the security boundary around a wildcard-scoped permission (e.g.
+/// `fsread:**` or `net:*`): the manifest can only be read once a provider
+/// has parsed it, which means the skill's files are already sitting in
+/// `target_path` by the time this runs. Refusing here is therefore a
+/// rollback, not a pre-install check -- unless `allow_wildcard` is set,
+/// this deletes `target_path` before erroring out, so a refusal doesn't
+/// leave an unvetted skill on disk. The read-time half is
+/// [`alltheskills::types::Permission::is_wildcard`] itself, already
+/// consulted by `permission ls` to flag over-broad grants after the fact.
+fn enforce_and_grant(skill: &alltheskills::Skill, target_path: &Path, capability: Option<&str>, allow_wildcard: bool) -> Result<(), anyhow::Error> {
+    let mut requested = skill.metadata.permissions.clone();
+    if let Some(id) = capability {
+        let capabilities = crate::permissions::load_capabilities()?;
+        let capability = capabilities
+            .capabilities
+            .get(id)
+            .ok_or_else(|| anyhow::anyhow!("No capability named '{id}'; define one with 'alltheskills capability new'"))?;
+        for permission in &capability.permissions {
+            if !requested.contains(permission) {
+                requested.push(permission.clone());
+            }
         }
-    } else {
-        // Local path
-        println!("Installing skill from local path: {}", source);
+    }
 
-        let provider = LocalProvider;
-        let source = SkillSource::Local {
-            path: PathBuf::from(source),
-        };
+    if requested.is_empty() {
+        return Ok(());
+    }
 
-        provider.install(source, target_path).await?
-    };
+    println!("\nThis skill requests the following permissions:");
+    for permission in &requested {
+        let wildcard = if permission.is_wildcard() { "  ⚠️  over-broad wildcard scope" } else { "" };
+        println!("  - {permission:?}{wildcard}");
+    }
 
-    println!("Successfully installed skill: {}", skill_source.name);
+    if !allow_wildcard {
+        if let Some(wildcard) = requested.iter().find(|p| p.is_wildcard()) {
+            let _ = std::fs::remove_dir_all(target_path);
+            anyhow::bail!(
+                "Refusing to install: {wildcard:?} is a wildcard-scoped permission. \
+                 Re-run with --allow-wildcard to install anyway."
+            );
+        }
+    }
+
+    let mut grants = crate::permissions::load_grants()?;
+    let grant = grants.grants.entry(skill.id.clone()).or_default();
+    for permission in &requested {
+        if !grant.granted.contains(permission) {
+            grant.granted.push(permission.clone());
+        }
+    }
+    crate::permissions::save_grants(&grants)?;
+
+    Ok(())
+}
+
+/// Records a freshly installed git-backed skill's resolved commit in
+/// `alltheskills.lock`, so a later `update --locked` (or another machine
+/// checking out the same lockfile) reproduces exactly this install
+fn record_lock_entry(skill: &alltheskills::Skill) -> Result<(), anyhow::Error> {
+    let repo = git2::Repository::open(&skill.path)?;
+    let commit = repo.head()?.peel_to_commit()?.id().to_string();
+
+    let content_hash = alltheskills::core::hash_tree(&skill.path)?;
+
+    let mut lock = crate::lock::load_lock()?;
+    lock.skills.insert(
+        skill.id.clone(),
+        LockedSkill {
+            commit,
+            reference: "HEAD".to_string(),
+            version: skill.version.clone(),
+            previous_commit: None,
+            content_hash,
+        },
+    );
+    crate::lock::save_lock(&lock)?;
 
     Ok(())
 }
@@ -0,0 +1,41 @@
+//! Man page generation
+//!
+//! Renders roff man pages for `alltheskills` and each of its subcommands
+//! directly from the same `clap::Command` definition used to parse
+//! arguments, so they stay in sync as subcommands evolve.
+
+use std::io::Write;
+use std::path::Path;
+
+/// Writes a man page for `cmd`, and one for each of its subcommands, to
+/// `output_dir`; prints just the root page to stdout if `output_dir` is
+/// `None`
+pub fn write_man_pages(cmd: &clap::Command, output_dir: Option<&Path>) -> Result<(), anyhow::Error> {
+    match output_dir {
+        Some(dir) => {
+            std::fs::create_dir_all(dir)?;
+            write_man_page_to_dir(cmd, cmd.get_name(), dir)?;
+            for sub in cmd.get_subcommands() {
+                let page_name = format!("{}-{}", cmd.get_name(), sub.get_name());
+                write_man_page_to_dir(sub, &page_name, dir)?;
+            }
+        }
+        None => {
+            std::io::stdout().write_all(&render(cmd)?)?;
+        }
+    }
+    Ok(())
+}
+
+/// Renders `cmd`'s man page and writes it to `<dir>/<name>.1`
+fn write_man_page_to_dir(cmd: &clap::Command, name: &str, dir: &Path) -> Result<(), anyhow::Error> {
+    std::fs::write(dir.join(format!("{name}.1")), render(cmd)?)?;
+    Ok(())
+}
+
+/// Renders `cmd`'s man page to a roff buffer
+fn render(cmd: &clap::Command) -> Result<Vec<u8>, anyhow::Error> {
+    let mut buffer = Vec::new();
+    clap_mangen::Man::new(cmd.clone()).render(&mut buffer)?;
+    Ok(buffer)
+}
@@ -1,17 +1,27 @@
+pub mod completions;
+pub mod convert;
 pub mod export_skill;
 pub mod info;
 pub mod install;
 pub mod list;
+pub mod man;
+pub mod permission;
 pub mod remove;
+pub mod schema;
 pub mod search;
 pub mod update;
 pub mod validate;
 
+pub use completions::{write_completions, CompletionShell};
+pub use convert::convert_skill;
 pub use export_skill::export_as_skill;
 pub use info::info_skill;
 pub use install::install_skill;
 pub use list::list_skills;
+pub use man::write_man_pages;
+pub use permission::{capability_new, permission_add, permission_ls, permission_new, permission_rm};
 pub use remove::remove_skill;
+pub use schema::print_schema;
 pub use search::search_skills;
-pub use update::update_skill;
+pub use update::{rollback_skill, update_skill};
 pub use validate::validate_skill;
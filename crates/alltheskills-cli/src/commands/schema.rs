@@ -0,0 +1,15 @@
+use alltheskills::schema::ManifestFormat;
+
+/// Prints the JSON Schema for a recognized manifest format (e.g.
+/// `codex.json`, `claude.json`), for editor integration or CI tooling
+pub fn print_schema(format: &str) -> Result<(), anyhow::Error> {
+    let manifest_format = ManifestFormat::from_filename(format)
+        .or_else(|| ManifestFormat::all().iter().copied().find(|f| format!("{f:?}").eq_ignore_ascii_case(format)))
+        .ok_or_else(|| {
+            let known: Vec<&str> = ManifestFormat::all().iter().map(|f| f.filename()).collect();
+            anyhow::anyhow!("unknown manifest format '{format}'; expected one of: {}", known.join(", "))
+        })?;
+
+    println!("{}", serde_json::to_string_pretty(&manifest_format.schema())?);
+    Ok(())
+}
@@ -1,20 +1,14 @@
-use alltheskills::providers::{
-    ClaudeProvider, ClineProvider, CursorProvider, LocalProvider, MoltbotProvider,
-    OpenClawProvider, RooProvider,
-};
-use alltheskills::{AllSkillsConfig, SkillReader};
+use alltheskills::providers::register_builtin_providers;
+use alltheskills::utils::levenshtein_distance;
+use alltheskills::{Skill, SkillReader};
 
 pub async fn search_skills(query: &str) -> Result<(), anyhow::Error> {
-    let config = AllSkillsConfig::default();
-    let mut reader = SkillReader::new(config);
+    let config = crate::config::load_config()?;
+    let mut reader = SkillReader::new(config.clone());
 
-    reader.add_provider(ClaudeProvider);
-    reader.add_provider(ClineProvider);
-    reader.add_provider(CursorProvider);
-    reader.add_provider(RooProvider);
-    reader.add_provider(OpenClawProvider);
-    reader.add_provider(MoltbotProvider);
-    reader.add_provider(LocalProvider);
+    for (source, provider) in register_builtin_providers(&config.cache_dir).build_from_config(&config) {
+        reader.add_provider_for_source(&source, provider);
+    }
 
     let query_lower = query.to_lowercase();
 
@@ -30,7 +24,19 @@ pub async fn search_skills(query: &str) -> Result<(), anyhow::Error> {
         .await?;
 
     if skills.is_empty() {
-        println!("No skills found matching '{}'.", query);
+        let all_skills = reader.list_all_skills().await?;
+        let fuzzy = fuzzy_match(query, &all_skills);
+
+        if fuzzy.is_empty() {
+            println!("No skills found matching '{}'.", query);
+        } else {
+            println!("No exact matches for '{}'. Did you mean '{}'?\n", query, fuzzy[0].name);
+            for skill in &fuzzy {
+                println!("[{:?}] {}", skill.source_type, skill.name);
+                println!("  {}", skill.description);
+                println!();
+            }
+        }
     } else {
         println!("Found {} skill(s) matching '{}':\n", skills.len(), query);
         for skill in skills {
@@ -45,3 +51,27 @@ pub async fn search_skills(query: &str) -> Result<(), anyhow::Error> {
 
     Ok(())
 }
+
+/// Ranks `skills` by Levenshtein distance between `query` and each skill's
+/// name and tags, keeping candidates within a tolerance that scales with
+/// token length (distance `<= max(len / 3, 2)`, mirroring `cargo`'s
+/// mistyped-subcommand suggestions), and returns up to three closest
+/// matches, best first
+fn fuzzy_match<'a>(query: &str, skills: &'a [Skill]) -> Vec<&'a Skill> {
+    let query_lower = query.to_lowercase();
+
+    let mut scored: Vec<(usize, &Skill)> = skills
+        .iter()
+        .filter_map(|skill| {
+            std::iter::once(skill.name.as_str())
+                .chain(skill.metadata.tags.iter().map(String::as_str))
+                .map(|token| levenshtein_distance(&query_lower, &token.to_lowercase()))
+                .min()
+                .map(|distance| (distance, skill))
+        })
+        .filter(|(distance, _)| *distance <= query_lower.len().max(6) / 3)
+        .collect();
+
+    scored.sort_by_key(|(distance, _)| *distance);
+    scored.into_iter().take(3).map(|(_, skill)| skill).collect()
+}
@@ -1,37 +1,90 @@
-use alltheskills::providers::{
-    ClaudeProvider, ClineProvider, CloudflareProvider, CodexProvider, CursorProvider,
-    KiloProvider, LocalProvider, MoltbotProvider, OpenClawProvider, RooProvider, VercelProvider,
-};
-use alltheskills::{AllSkillsConfig, SkillReader};
-use std::path::PathBuf;
-
-pub async fn update_skill(name: Option<&str>) -> Result<(), anyhow::Error> {
-    let config = AllSkillsConfig::default();
-    let mut reader = SkillReader::new(config);
-
-    // Add all providers
-    reader.add_provider(ClaudeProvider);
-    reader.add_provider(ClineProvider);
-    reader.add_provider(CursorProvider);
-    reader.add_provider(RooProvider);
-    reader.add_provider(OpenClawProvider);
-    reader.add_provider(MoltbotProvider);
-    reader.add_provider(CodexProvider);
-    reader.add_provider(KiloProvider);
-    reader.add_provider(VercelProvider);
-    reader.add_provider(CloudflareProvider);
-    reader.add_provider(LocalProvider);
+use alltheskills::providers::register_builtin_providers;
+use alltheskills::types::LockedSkill;
+use alltheskills::SkillReader;
+use futures::stream::{self, StreamExt};
+use std::path::Path;
+
+/// Outcome of resolving a skill's git remote to a commit
+struct UpdateOutcome {
+    updated: bool,
+    commit: git2::Oid,
+    reference: String,
+    /// HEAD before this update ran, recorded so a failed update (or a
+    /// later `update --rollback`) can restore it
+    previous_commit: git2::Oid,
+    /// [`alltheskills::core::hash_tree`] of the skill's directory once it
+    /// reflects `commit`, recorded into the lockfile alongside it
+    content_hash: String,
+}
+
+/// Per-skill result of a concurrent update run, collected into a summary
+/// rather than printed as each task completes, since interleaving
+/// `println!` across concurrent tasks produces garbled output.
+enum UpdateResult {
+    Updated {
+        id: String,
+        name: String,
+        version: Option<String>,
+        outcome: UpdateOutcome,
+    },
+    UpToDate {
+        id: String,
+        name: String,
+        version: Option<String>,
+        outcome: UpdateOutcome,
+    },
+    Skipped {
+        name: String,
+    },
+    /// `verify` found the on-disk tree no longer matches the hash
+    /// recorded in `alltheskills.lock`, and `--allow-drift` wasn't passed
+    Drifted {
+        name: String,
+    },
+    Failed {
+        name: String,
+        error: anyhow::Error,
+    },
+}
+
+/// Update skills, optionally pinning to the commits recorded in `alltheskills.lock`
+///
+/// Runs up to `jobs` updates concurrently -- each git-backed skill's update
+/// is network-bound but uses `git2`'s blocking API, so every update runs
+/// on a blocking thread via `spawn_blocking` while `buffer_unordered`
+/// caps how many run at once. When `locked` is `true`, each git-backed
+/// skill is checked out at its recorded OID instead of fetching the
+/// latest commit on its branch. In both modes, the resolved commit is
+/// (re-)written to the lockfile so a second machine running
+/// `update --locked` reproduces the same tree.
+///
+/// Before fetching, each skill with a recorded `content_hash` is passed
+/// through [`GitHubProvider::verify`](alltheskills::providers::github::GitHubProvider)
+/// to catch drift -- files changed on disk since the last install/update
+/// that isn't explained by a new commit. Unless `allow_drift` is set,
+/// drifted skills are left untouched (no fetch, no lockfile rewrite)
+/// rather than silently overwriting whatever the drift was.
+///
+/// Note: lock entries written before tree hashing existed carry the old
+/// metadata-string hash, which never matches a real `hash_tree` digest --
+/// the first `update` after upgrading reports every such skill as
+/// drifted once, until `--allow-drift` re-records it with a real hash.
+pub async fn update_skill(name: Option<&str>, locked: bool, jobs: usize, allow_drift: bool) -> Result<(), anyhow::Error> {
+    let config = crate::config::load_config()?;
+    let mut reader = SkillReader::new(config.clone());
+
+    for (source, provider) in register_builtin_providers(&config.cache_dir).build_from_config(&config) {
+        reader.add_provider_for_source(&source, provider);
+    }
 
     let skills = reader.list_all_skills().await?;
+    let mut lock = crate::lock::load_lock()?;
 
-    if let Some(name) = name {
-        // Update specific skill
+    let targets: Vec<alltheskills::Skill> = if let Some(name) = name {
         let name_lower = name.to_lowercase();
         let matching: Vec<_> = skills
-            .iter()
-            .filter(|s| {
-                s.name.to_lowercase() == name_lower || s.id.to_lowercase() == name_lower
-            })
+            .into_iter()
+            .filter(|s| s.name.to_lowercase() == name_lower || s.id.to_lowercase() == name_lower)
             .collect();
 
         if matching.is_empty() {
@@ -39,63 +92,238 @@ pub async fn update_skill(name: Option<&str>) -> Result<(), anyhow::Error> {
             return Ok(());
         }
 
-        for skill in matching {
-            update_single_skill(skill).await?;
-        }
+        matching
     } else {
-        // Update all skills
         println!("Checking for updates for {} skill(s)...", skills.len());
-        for skill in &skills {
-            update_single_skill(skill).await?;
+        skills
+    };
+
+    let jobs = jobs.max(1);
+    let tasks = targets.into_iter().map(|mut skill| {
+        let locked_commit = locked
+            .then(|| lock.skills.get(&skill.id).map(|l| l.commit.clone()))
+            .flatten();
+        skill.metadata.content_hash = lock.skills.get(&skill.id).map(|l| l.content_hash.clone());
+        async move { run_update(skill, locked_commit, allow_drift).await }
+    });
+
+    let results: Vec<UpdateResult> = stream::iter(tasks).buffer_unordered(jobs).collect().await;
+
+    let (mut updated, mut up_to_date, mut skipped, mut drifted, mut failed) = (0, 0, 0, 0, 0);
+    for result in results {
+        match result {
+            UpdateResult::Updated {
+                id,
+                name,
+                version,
+                outcome,
+            } => {
+                println!("✅ {name}: updated to {}", outcome.reference);
+                updated += 1;
+                lock.skills.insert(
+                    id,
+                    LockedSkill {
+                        commit: outcome.commit.to_string(),
+                        reference: outcome.reference,
+                        version,
+                        previous_commit: Some(outcome.previous_commit.to_string()),
+                        content_hash: outcome.content_hash,
+                    },
+                );
+            }
+            UpdateResult::UpToDate {
+                id,
+                name,
+                version,
+                outcome,
+            } => {
+                println!("ℹ️  {name}: already up to date");
+                up_to_date += 1;
+                lock.skills.insert(
+                    id,
+                    LockedSkill {
+                        commit: outcome.commit.to_string(),
+                        reference: outcome.reference,
+                        version,
+                        previous_commit: Some(outcome.previous_commit.to_string()),
+                        content_hash: outcome.content_hash,
+                    },
+                );
+            }
+            UpdateResult::Skipped { name } => {
+                println!("⏭️  {name}: skipped (not git-backed)");
+                skipped += 1;
+            }
+            UpdateResult::Drifted { name } => {
+                println!(
+                    "⚠️  {name}: on-disk tree doesn't match alltheskills.lock; skipping (pass --allow-drift to update anyway)"
+                );
+                drifted += 1;
+            }
+            UpdateResult::Failed { name, error } => {
+                println!("❌ {name}: update failed: {error}");
+                failed += 1;
+            }
         }
     }
 
+    crate::lock::save_lock(&lock)?;
+
+    println!(
+        "\n{updated} updated, {up_to_date} up to date, {skipped} skipped, {drifted} drifted, {failed} failed"
+    );
+
     Ok(())
 }
 
-async fn update_single_skill(skill: &alltheskills::Skill) -> Result<(), anyhow::Error> {
+/// Runs one skill's update on a blocking thread, since `git2` is
+/// synchronous, and converts the outcome into an [`UpdateResult`]
+///
+/// Before touching the working tree, checks `skill` against its recorded
+/// `content_hash` (if any) via [`SkillProvider::verify`], refusing to
+/// proceed -- unless `allow_drift` is set -- when the tree has drifted
+/// since the last install/update.
+async fn run_update(skill: alltheskills::Skill, locked_commit: Option<String>, allow_drift: bool) -> UpdateResult {
+    use alltheskills::providers::github::GitHubProvider;
     use alltheskills::types::SkillSource;
+    use alltheskills::SkillProvider;
 
-    match &skill.source {
+    let (owner, repo, branch, version_req) = match &skill.source {
         SkillSource::GitHub {
             owner,
             repo,
-            subdir: _,
             branch,
-        } => {
-            println!("Updating {} (GitHub: {}/{})...", skill.name, owner, repo);
-            match update_git_skill(&skill.path, owner, repo, branch.as_deref()).await {
-                Ok(updated) => {
-                    if updated {
-                        println!("  ✅ Updated successfully");
-                    } else {
-                        println!("  ℹ️  Already up to date");
-                    }
-                }
-                Err(e) => {
-                    println!("  ❌ Update failed: {}", e);
-                }
+            version_req,
+            ..
+        } => (owner.clone(), repo.clone(), branch.clone(), version_req.clone()),
+        // `Oci` is tag-addressed like a container image, not a git branch
+        // that can be fast-forwarded -- re-pulling the same tag would just
+        // redownload an identical artifact, and tracking a *new* tag isn't
+        // "updating" in the sense this command means, so it's skipped the
+        // same as `Local`/`Remote`.
+        SkillSource::Local { .. } | SkillSource::Remote { .. } | SkillSource::Oci { .. } => {
+            return UpdateResult::Skipped { name: skill.name };
+        }
+    };
+
+    if !allow_drift {
+        match GitHubProvider.verify(&skill).await {
+            Ok(false) => return UpdateResult::Drifted { name: skill.name },
+            Ok(true) => {}
+            Err(error) => return UpdateResult::Failed { name: skill.name, error: error.into() },
+        }
+    }
+
+    let path = skill.path.clone();
+    let name = skill.name.clone();
+    let id = skill.id.clone();
+    let version = skill.version.clone();
+
+    let outcome = tokio::task::spawn_blocking(move || {
+        update_git_skill(
+            &path,
+            &owner,
+            &repo,
+            branch.as_deref(),
+            version_req.as_deref(),
+            locked_commit.as_deref(),
+        )
+    })
+    .await;
+
+    match outcome {
+        Ok(Ok(outcome)) if outcome.updated => UpdateResult::Updated {
+            id,
+            name,
+            version,
+            outcome,
+        },
+        Ok(Ok(outcome)) => UpdateResult::UpToDate {
+            id,
+            name,
+            version,
+            outcome,
+        },
+        Ok(Err(error)) => UpdateResult::Failed { name, error },
+        Err(join_error) => UpdateResult::Failed {
+            name,
+            error: anyhow::anyhow!("update task panicked: {join_error}"),
+        },
+    }
+}
+
+/// Build the credential callback used for authenticated `fetch`es of
+/// private skill repos.
+///
+/// Tries, in order: an SSH agent key for `git@`-style remotes, then an
+/// HTTPS token read from `GITHUB_TOKEN` (falling back to `GIT_TOKEN`),
+/// then `git2`'s default credential (`Cred::default`, used for NTLM/Negotiate
+/// authentication).
+fn remote_callbacks() -> git2::RemoteCallbacks<'static> {
+    let mut callbacks = git2::RemoteCallbacks::new();
+    callbacks.credentials(|_url, username_from_url, allowed| {
+        if allowed.contains(git2::CredentialType::SSH_KEY) {
+            if let Some(username) = username_from_url {
+                return git2::Cred::ssh_key_from_agent(username);
             }
         }
-        SkillSource::Local { path: _ } => {
-            // Local skills can't be automatically updated
-            println!("Skipping {} (local skill)", skill.name);
+
+        if allowed.contains(git2::CredentialType::USER_PASS_PLAINTEXT) {
+            if let Ok(token) = std::env::var("GITHUB_TOKEN").or_else(|_| std::env::var("GIT_TOKEN"))
+            {
+                return git2::Cred::userpass_plaintext(username_from_url.unwrap_or("x-access-token"), &token);
+            }
         }
-        SkillSource::Remote { url, .. } => {
-            println!("Updating {} from {}...", skill.name, url);
-            println!("  Note: Remote skill updates not yet implemented");
+
+        git2::Cred::default()
+    });
+    callbacks
+}
+
+/// Highest tag satisfying `version_req` among the repository's semver tags
+/// (with or without a leading `v`), if any, paired with the commit it
+/// points at
+fn highest_satisfying_tag(
+    repo: &git2::Repository,
+    version_req: &str,
+) -> Result<Option<(String, git2::Oid)>, anyhow::Error> {
+    let req = semver::VersionReq::parse(version_req)?;
+
+    let mut best: Option<(semver::Version, String, git2::Oid)> = None;
+    for tag_name in repo.tag_names(None)?.iter().flatten() {
+        let Ok(version) = semver::Version::parse(tag_name.trim_start_matches('v')) else {
+            continue;
+        };
+        if !req.matches(&version) {
+            continue;
+        }
+
+        let oid = repo
+            .revparse_single(&format!("refs/tags/{tag_name}^{{commit}}"))?
+            .id();
+
+        if best.as_ref().is_none_or(|(best_version, ..)| version > *best_version) {
+            best = Some((version, tag_name.to_string(), oid));
         }
     }
 
-    Ok(())
+    Ok(best.map(|(_, name, oid)| (name, oid)))
 }
 
-async fn update_git_skill(
-    path: &PathBuf,
+/// Fetches and checks out a git-backed skill's update; blocking, run via
+/// `spawn_blocking` from [`run_update`].
+///
+/// When `version_req` is set and the skill isn't `--locked`, the update
+/// tracks the highest semver tag satisfying it instead of the branch tip,
+/// falling back to the usual branch fast-forward when no tag matches.
+fn update_git_skill(
+    path: &Path,
     _owner: &str,
     _repo: &str,
     branch: Option<&str>,
-) -> Result<bool, anyhow::Error> {
+    version_req: Option<&str>,
+    locked_commit: Option<&str>,
+) -> Result<UpdateOutcome, anyhow::Error> {
     if !path.exists() {
         anyhow::bail!("Skill directory does not exist: {}", path.display());
     }
@@ -114,48 +342,195 @@ async fn update_git_skill(
     let head = repo.head()?;
     let current_oid = head.target().ok_or_else(|| anyhow::anyhow!("No target for HEAD"))?;
 
-    // Fetch updates from origin
-    println!("  Fetching updates from origin...");
-
-    // Perform fetch
+    // Perform fetch, authenticating against private/SSH remotes; fetch all
+    // tags so `version_req` tracking has the full set to search, not just
+    // tags reachable from already-fetched branch history
     let mut remote = repo.find_remote("origin")?;
-    remote.fetch(&[] as &[&str], None, None)?;
+    let mut fetch_options = git2::FetchOptions::new();
+    fetch_options.remote_callbacks(remote_callbacks());
+    fetch_options.download_tags(git2::AutotagOption::All);
+    remote.fetch(&[] as &[&str], Some(&mut fetch_options), None)?;
 
-    // Determine which reference to merge
+    // Determine which reference to merge, and its short name for the lockfile
     let ref_name = branch.map(|b| format!("refs/remotes/origin/{}", b));
-    let reference = if let Some(ref_name) = ref_name {
-        repo.find_reference(&ref_name)?
+    let (reference, reference_name) = if let Some(ref_name) = ref_name {
+        (repo.find_reference(&ref_name)?, branch.unwrap().to_string())
     } else {
         // Use origin/HEAD or origin/main or origin/master
         let refs = ["origin/HEAD", "origin/main", "origin/master"];
         let mut found_ref = None;
         for r in &refs {
             if let Ok(reference) = repo.find_reference(&format!("refs/remotes/{}", r)) {
-                found_ref = Some(reference);
+                found_ref = Some((reference, r.to_string()));
                 break;
             }
         }
         found_ref.ok_or_else(|| anyhow::anyhow!("Could not find default branch"))?
     };
 
-    let new_oid = reference.target().ok_or_else(|| anyhow::anyhow!("No target for reference"))?;
+    // A skill tracking a `version_req` checks out the highest satisfying
+    // tag instead of the branch tip, falling back to the branch when no
+    // tag matches
+    let tag_pin = match version_req {
+        Some(req) if locked_commit.is_none() => highest_satisfying_tag(&repo, req)?,
+        _ => None,
+    };
+
+    // In `--locked` mode, pin to the recorded OID instead of the branch tip
+    let (new_oid, reference_name) = match locked_commit {
+        Some(commit) => (git2::Oid::from_str(commit)?, reference_name),
+        None => match tag_pin {
+            Some((tag_name, tag_oid)) => (tag_oid, tag_name),
+            None => (
+                reference
+                    .target()
+                    .ok_or_else(|| anyhow::anyhow!("No target for reference"))?,
+                reference_name,
+            ),
+        },
+    };
 
     // Check if there are updates
     if current_oid == new_oid {
-        return Ok(false); // Already up to date
+        return Ok(UpdateOutcome {
+            updated: false,
+            commit: current_oid,
+            reference: reference_name,
+            previous_commit: current_oid,
+            content_hash: alltheskills::core::hash_tree(path)?,
+        });
     }
 
-    // Perform merge (fast-forward only for safety)
-    let annotated_commit = repo.find_annotated_commit(new_oid)?;
-    repo.merge(&[&annotated_commit], None, None)?;
+    if locked_commit.is_some() || tag_pin_active(new_oid, &reference) {
+        // A lock pin, or a tag pin at a commit other than the branch tip,
+        // may move the tree backwards relative to its current HEAD,
+        // which a fast-forward merge can't express, so reset hard to the
+        // exact target commit instead.
+        let object = repo.find_object(new_oid, None)?;
+        repo.reset(&object, git2::ResetType::Hard, None)?;
+    } else {
+        // Perform merge (fast-forward only for safety)
+        let annotated_commit = repo.find_annotated_commit(new_oid)?;
+        repo.merge(&[&annotated_commit], None, None)?;
 
-    // Clean up merge state
-    repo.cleanup_state()?;
+        // Clean up merge state
+        repo.cleanup_state()?;
+
+        // Update HEAD to point to new commit
+        let ref_name = format!("refs/heads/{}", branch.unwrap_or("main"));
+        let mut local_ref = repo.find_reference(&ref_name)?;
+        local_ref.set_target(new_oid, "Fast-forward merge")?;
+    }
+
+    // Validate the updated tree still looks like a skill before declaring
+    // success; a broken fetch or an upstream change that drops the
+    // manifest shouldn't leave the skill half-updated with no way back.
+    if !skill_still_parses(path) {
+        let object = repo.find_object(current_oid, None)?;
+        repo.reset(&object, git2::ResetType::Hard, None)?;
+        repo.cleanup_state()?;
+        anyhow::bail!(
+            "update to {reference_name} ({new_oid}) no longer looks like a valid skill; rolled back to {current_oid}"
+        );
+    }
+
+    Ok(UpdateOutcome {
+        updated: true,
+        commit: new_oid,
+        reference: reference_name,
+        previous_commit: current_oid,
+        content_hash: alltheskills::core::hash_tree(path)?,
+    })
+}
 
-    // Update HEAD to point to new commit
-    let ref_name = format!("refs/heads/{}", branch.unwrap_or("main"));
-    let mut local_ref = repo.find_reference(&ref_name)?;
-    local_ref.set_target(new_oid, "Fast-forward merge")?;
+/// Whether `new_oid` came from a tag pin rather than `reference` itself,
+/// i.e. whether the usual branch fast-forward machinery doesn't apply
+fn tag_pin_active(new_oid: git2::Oid, reference: &git2::Reference) -> bool {
+    reference.target().is_none_or(|target| target != new_oid)
+}
+
+/// Recognized manifest filenames, same set [`crate::commands::validate`]
+/// checks for; a lightweight stand-in for re-parsing via the owning
+/// provider, which would require exposing each provider's private
+/// directory parser publicly
+const KNOWN_MANIFESTS: &[&str] = &[
+    "claude.json",
+    "cline.json",
+    "cursor.json",
+    ".cursorrules",
+    "roo.json",
+    ".roomodes",
+    "manifest.json",
+    "skill.json",
+    "codex.json",
+    "kilo.yaml",
+    "kilo.yml",
+    "wrangler.toml",
+];
+
+/// Whether `path` still contains a recognizable skill manifest after an
+/// update, used to decide whether to roll the update back
+fn skill_still_parses(path: &Path) -> bool {
+    path.is_dir() && KNOWN_MANIFESTS.iter().any(|name| path.join(name).exists())
+}
+
+/// Rolls a skill back to the commit it was pinned to before its last
+/// `update`, as recorded in `alltheskills.lock`
+pub async fn rollback_skill(name: &str) -> Result<(), anyhow::Error> {
+    let config = crate::config::load_config()?;
+    let mut reader = SkillReader::new(config.clone());
+    for (source, provider) in register_builtin_providers(&config.cache_dir).build_from_config(&config) {
+        reader.add_provider_for_source(&source, provider);
+    }
+
+    let name_lower = name.to_lowercase();
+    let skill = reader
+        .list_all_skills()
+        .await?
+        .into_iter()
+        .find(|s| s.name.to_lowercase() == name_lower || s.id.to_lowercase() == name_lower)
+        .ok_or_else(|| anyhow::anyhow!("Skill '{name}' not found."))?;
+
+    let mut lock = crate::lock::load_lock()?;
+    let locked = lock
+        .skills
+        .get(&skill.id)
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("No update history recorded for '{}'.", skill.name))?;
+    let previous_commit = locked
+        .previous_commit
+        .clone()
+        .ok_or_else(|| anyhow::anyhow!("No previous commit recorded for '{}' to roll back to.", skill.name))?;
+
+    let path = skill.path.clone();
+    let target = previous_commit.clone();
+    tokio::task::spawn_blocking(move || reset_to_commit(&path, &target)).await??;
 
-    Ok(true)
+    println!("⏪ {}: rolled back to {previous_commit}", skill.name);
+
+    let content_hash = alltheskills::core::hash_tree(&skill.path)?;
+    lock.skills.insert(
+        skill.id,
+        LockedSkill {
+            commit: previous_commit,
+            reference: locked.reference,
+            version: locked.version,
+            previous_commit: None,
+            content_hash,
+        },
+    );
+    crate::lock::save_lock(&lock)?;
+
+    Ok(())
+}
+
+/// Hard-resets `path`'s repository to `commit`, run via `spawn_blocking`
+/// from [`rollback_skill`]
+fn reset_to_commit(path: &Path, commit: &str) -> Result<(), anyhow::Error> {
+    let repo = git2::Repository::open(path)?;
+    let oid = git2::Oid::from_str(commit)?;
+    let object = repo.find_object(oid, None)?;
+    repo.reset(&object, git2::ResetType::Hard, None)?;
+    repo.cleanup_state()?;
+    Ok(())
 }
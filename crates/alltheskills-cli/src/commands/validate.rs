@@ -1,11 +1,36 @@
 use alltheskills::providers::{
     ClaudeProvider, ClineProvider, CloudflareProvider, CodexProvider, CursorProvider,
-    KiloProvider, LocalProvider, MoltbotProvider, OpenClawProvider, RooProvider, VercelProvider,
+    DiagnosticSeverity, KiloProvider, LocalProvider, MoltbotProvider, OpenClawProvider, RooProvider,
+    VercelProvider,
 };
-use alltheskills::{AllSkillsConfig, SkillReader};
+use alltheskills::schema::ManifestFormat;
+use alltheskills::types::{Skill, SkillFormat, SourceType};
+use alltheskills::{AllSkillsConfig, SkillProvider, SkillReader};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
-pub async fn validate_skill(path: Option<&str>) -> Result<(), anyhow::Error> {
+/// How serious a [`LintFinding`] is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    /// Likely to break loading the skill; fails the lint run
+    Error,
+    /// Worth fixing but doesn't block loading
+    Warning,
+}
+
+/// A single problem found while linting a skill
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LintFinding {
+    pub skill_id: String,
+    pub provider: String,
+    pub severity: LintSeverity,
+    pub message: String,
+}
+
+pub async fn validate_skill(path: Option<&str>, format: &str) -> Result<(), anyhow::Error> {
+    let json_output = format.eq_ignore_ascii_case("json");
+
     let config = AllSkillsConfig::default();
     let mut reader = SkillReader::new(config);
 
@@ -24,39 +49,310 @@ pub async fn validate_skill(path: Option<&str>) -> Result<(), anyhow::Error> {
 
     if let Some(path) = path {
         // Validate specific skill directory
-        validate_single_skill(PathBuf::from(path)).await?;
+        validate_single_skill(PathBuf::from(path), json_output).await?;
     } else {
-        // Validate all installed skills
+        // Lint all installed skills across every registered provider
         let skills = reader.list_all_skills().await?;
-        println!("Validating {} skill(s)...\n", skills.len());
 
-        let mut valid_count = 0;
-        let mut invalid_count = 0;
+        let mut findings = lint_skills(&skills);
+        findings.extend(provider_diagnostics(&skills).await);
+        findings.extend(schema_findings(&skills));
 
-        for skill in &skills {
-            match validate_skill_structure(&skill.path, &format!("{:?}", skill.source_type)).await {
-                Ok(()) => {
-                    println!("✅ {} - Valid", skill.name);
-                    valid_count += 1;
-                }
-                Err(e) => {
-                    println!("❌ {} - Invalid: {}", skill.name, e);
-                    invalid_count += 1;
+        if json_output {
+            println!("{}", serde_json::to_string_pretty(&findings)?);
+        } else {
+            println!("Linting {} skill(s)...\n", skills.len());
+            print_lint_report(&skills, &findings);
+        }
+
+        if findings.iter().any(|f| f.severity == LintSeverity::Error) {
+            anyhow::bail!("lint found error-level problems");
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates each skill's on-disk manifest (`codex.json`, `claude.json`,
+/// ...) against its format's JSON Schema, via [`ManifestFormat::validate`],
+/// reporting structural problems (missing fields, wrong types) instead of
+/// just "valid JSON"
+fn schema_findings(skills: &[Skill]) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+
+    for skill in skills {
+        let Some(manifest_format) = schema_format_for(&skill.format) else {
+            continue;
+        };
+        let manifest_path = skill.path.join(manifest_format.filename());
+        if !manifest_path.exists() {
+            continue;
+        }
+
+        let provider = format!("{:?}", skill.source_type);
+        let content = match std::fs::read_to_string(&manifest_path) {
+            Ok(content) => content,
+            Err(e) => {
+                findings.push(LintFinding {
+                    skill_id: skill.id.clone(),
+                    provider,
+                    severity: LintSeverity::Error,
+                    message: format!("{}: couldn't read manifest: {e}", manifest_format.filename()),
+                });
+                continue;
+            }
+        };
+
+        let value: serde_json::Value = match serde_json::from_str(&content) {
+            Ok(value) => value,
+            Err(e) => {
+                findings.push(LintFinding {
+                    skill_id: skill.id.clone(),
+                    provider,
+                    severity: LintSeverity::Error,
+                    message: format!("{}: invalid JSON: {e}", manifest_format.filename()),
+                });
+                continue;
+            }
+        };
+
+        match manifest_format.validate(&value) {
+            Ok(errors) => {
+                for error in errors {
+                    findings.push(LintFinding {
+                        skill_id: skill.id.clone(),
+                        provider: provider.clone(),
+                        severity: LintSeverity::Error,
+                        message: format!("{}: {error}", manifest_format.filename()),
+                    });
                 }
             }
+            Err(e) => findings.push(LintFinding {
+                skill_id: skill.id.clone(),
+                provider,
+                severity: LintSeverity::Error,
+                message: format!("{}: schema validation failed: {e}", manifest_format.filename()),
+            }),
         }
+    }
 
-        println!(
-            "\nValidation complete: {} valid, {} invalid",
-            valid_count, invalid_count
-        );
+    findings
+}
+
+/// Maps a [`SkillFormat`] to the [`ManifestFormat`] whose schema its JSON
+/// manifest should be checked against, if it has one
+fn schema_format_for(format: &SkillFormat) -> Option<ManifestFormat> {
+    match format {
+        SkillFormat::ClaudeSkill | SkillFormat::ClaudePlugin => Some(ManifestFormat::Claude),
+        SkillFormat::ClineSkill => Some(ManifestFormat::Cline),
+        SkillFormat::CursorRules => Some(ManifestFormat::Cursor),
+        SkillFormat::RooSkill => Some(ManifestFormat::Roo),
+        SkillFormat::CodexSkill => Some(ManifestFormat::Codex),
+        SkillFormat::MoltbotSkill => Some(ManifestFormat::Moltbot),
+        SkillFormat::OpenClawSkill => Some(ManifestFormat::OpenClaw),
+        SkillFormat::KiloSkill
+        | SkillFormat::GenericMarkdown
+        | SkillFormat::GenericJson
+        | SkillFormat::Unknown => None,
     }
+}
 
-    Ok(())
+/// Runs structural checks against every skill and returns every problem
+/// found, rather than stopping at the first one. Findings always include
+/// the offending `skill_id` and `provider` so callers can group them.
+pub fn lint_skills(skills: &[Skill]) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+    let mut seen_ids: HashMap<String, String> = HashMap::new();
+
+    for skill in skills {
+        let provider = format!("{:?}", skill.source_type);
+
+        if !skill.path.exists() {
+            findings.push(LintFinding {
+                skill_id: skill.id.clone(),
+                provider: provider.clone(),
+                severity: LintSeverity::Error,
+                message: format!("path does not exist: {}", skill.path.display()),
+            });
+        }
+
+        if !has_readable_content(skill) {
+            findings.push(LintFinding {
+                skill_id: skill.id.clone(),
+                provider: provider.clone(),
+                severity: LintSeverity::Error,
+                message: "no readable content file for this skill's format".to_string(),
+            });
+        }
+
+        if skill.name.trim().is_empty() {
+            findings.push(LintFinding {
+                skill_id: skill.id.clone(),
+                provider: provider.clone(),
+                severity: LintSeverity::Error,
+                message: "name is empty".to_string(),
+            });
+        }
+
+        if skill.description.trim().is_empty() {
+            findings.push(LintFinding {
+                skill_id: skill.id.clone(),
+                provider: provider.clone(),
+                severity: LintSeverity::Warning,
+                message: "description is empty".to_string(),
+            });
+        }
+
+        for permission in &skill.metadata.permissions {
+            if permission_identifier(permission).trim().is_empty() {
+                findings.push(LintFinding {
+                    skill_id: skill.id.clone(),
+                    provider: provider.clone(),
+                    severity: LintSeverity::Error,
+                    message: format!("permission {permission:?} has an empty identifier"),
+                });
+            } else if permission.is_wildcard() {
+                findings.push(LintFinding {
+                    skill_id: skill.id.clone(),
+                    provider: provider.clone(),
+                    severity: LintSeverity::Warning,
+                    message: format!("permission {permission:?} requests an over-broad wildcard scope"),
+                });
+            }
+        }
+
+        if let Some(previous_provider) = seen_ids.get(&skill.id) {
+            findings.push(LintFinding {
+                skill_id: skill.id.clone(),
+                provider: provider.clone(),
+                severity: LintSeverity::Error,
+                message: format!(
+                    "duplicate id, also produced by provider `{previous_provider}`"
+                ),
+            });
+        } else {
+            seen_ids.insert(skill.id.clone(), provider);
+        }
+    }
+
+    findings
 }
 
-async fn validate_single_skill(path: PathBuf) -> Result<(), anyhow::Error> {
-    println!("Validating skill at: {}\n", path.display());
+/// Runs each skill's own provider's `validate()` and converts the resulting
+/// `Diagnostic`s into `LintFinding`s, so format-specific problems (a
+/// malformed `manifest.json`, an empty `.cursorrules`, ...) show up
+/// alongside the generic structural checks from `lint_skills`.
+async fn provider_diagnostics(skills: &[Skill]) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+
+    for skill in skills {
+        let provider = provider_for(&skill.source_type);
+        for diagnostic in provider.validate(skill).await {
+            findings.push(LintFinding {
+                skill_id: skill.id.clone(),
+                provider: format!("{:?}", skill.source_type),
+                severity: match diagnostic.severity {
+                    DiagnosticSeverity::Error => LintSeverity::Error,
+                    DiagnosticSeverity::Warning => LintSeverity::Warning,
+                },
+                message: match diagnostic.file {
+                    Some(file) => format!("{file}: {}", diagnostic.message),
+                    None => diagnostic.message,
+                },
+            });
+        }
+    }
+
+    findings
+}
+
+/// Extracts the identifier a permission resolves to, for checking it's
+/// non-empty (an empty tool name or glob can't be enforced against anything)
+fn permission_identifier(permission: &alltheskills::types::Permission) -> String {
+    use alltheskills::types::Permission;
+    match permission {
+        Permission::Tool(name) => name.clone(),
+        Permission::FsRead(glob) | Permission::FsWrite(glob) => glob.clone(),
+        Permission::Net(host) => host.clone(),
+    }
+}
+
+/// Picks the provider instance that understands `source_type`'s on-disk
+/// layout well enough to call `validate()` on one of its skills.
+fn provider_for(source_type: &SourceType) -> Box<dyn SkillProvider> {
+    match source_type {
+        SourceType::Claude => Box::new(ClaudeProvider),
+        SourceType::Cline => Box::new(ClineProvider),
+        SourceType::Cursor => Box::new(CursorProvider),
+        SourceType::RooCode => Box::new(RooProvider),
+        SourceType::OpenClaw => Box::new(OpenClawProvider),
+        SourceType::Moltbot => Box::new(MoltbotProvider),
+        SourceType::OpenAICodex => Box::new(CodexProvider),
+        SourceType::KiloCode => Box::new(KiloProvider),
+        _ => Box::new(LocalProvider),
+    }
+}
+
+/// Checks that the backing path for `skill` has a file appropriate for its
+/// [`SkillFormat`] that we could actually read content from.
+fn has_readable_content(skill: &Skill) -> bool {
+    let expected_files: &[&str] = match skill.format {
+        SkillFormat::ClaudeSkill | SkillFormat::ClaudePlugin => &["claude.json", "skill.md", "README.md"],
+        SkillFormat::ClineSkill => &["cline.json", "custom-instructions.md", "README.md"],
+        SkillFormat::CursorRules => &[".cursorrules", "cursor.json", "README.md"],
+        SkillFormat::RooSkill => &["roo.json", ".roomodes", "README.md"],
+        SkillFormat::CodexSkill => &["codex.json", "instructions.md", "README.md"],
+        SkillFormat::KiloSkill => &["kilo.yaml", "kilo.yml", "instructions.md", "README.md"],
+        SkillFormat::MoltbotSkill => &["manifest.json", "SKILL.md", "README.md"],
+        SkillFormat::OpenClawSkill => &["skill.json", "README.md"],
+        SkillFormat::GenericMarkdown => &["README.md"],
+        SkillFormat::GenericJson => &["README.md"],
+        SkillFormat::Unknown => &["README.md"],
+    };
+
+    expected_files.iter().any(|file| skill.path.join(file).exists())
+}
+
+/// Prints findings grouped by provider, most severe first
+fn print_lint_report(skills: &[Skill], findings: &[LintFinding]) {
+    let error_count = findings
+        .iter()
+        .filter(|f| f.severity == LintSeverity::Error)
+        .count();
+    let warning_count = findings.len() - error_count;
+
+    let mut by_provider: HashMap<&str, Vec<&LintFinding>> = HashMap::new();
+    for finding in findings {
+        by_provider.entry(&finding.provider).or_default().push(finding);
+    }
+
+    let mut providers: Vec<&&str> = by_provider.keys().collect();
+    providers.sort();
+
+    for provider in providers {
+        println!("{provider}:");
+        for finding in &by_provider[*provider] {
+            let icon = match finding.severity {
+                LintSeverity::Error => "❌",
+                LintSeverity::Warning => "⚠️ ",
+            };
+            println!("  {icon} {} - {}", finding.skill_id, finding.message);
+        }
+        println!();
+    }
+
+    println!(
+        "Linted {} skill(s): {} error(s), {} warning(s)",
+        skills.len(),
+        error_count,
+        warning_count
+    );
+}
+
+async fn validate_single_skill(path: PathBuf, json_output: bool) -> Result<(), anyhow::Error> {
+    if !json_output {
+        println!("Validating skill at: {}\n", path.display());
+    }
 
     if !path.exists() {
         anyhow::bail!("Path does not exist: {}", path.display());
@@ -69,6 +365,7 @@ async fn validate_single_skill(path: PathBuf) -> Result<(), anyhow::Error> {
     // Check for required files
     let mut has_manifest = false;
     let mut has_readme = false;
+    let mut schema_errors: Vec<String> = Vec::new();
 
     // Check for various manifest files
     let manifest_files = [
@@ -88,14 +385,48 @@ async fn validate_single_skill(path: PathBuf) -> Result<(), anyhow::Error> {
     for file in &manifest_files {
         if path.join(file).exists() {
             has_manifest = true;
-            println!("✅ Found manifest: {}", file);
+            if !json_output {
+                println!("✅ Found manifest: {}", file);
+            }
 
-            // Validate JSON files
+            // Validate JSON files against their schema, if we have one
             if file.ends_with(".json") && !file.starts_with(".") {
                 let content = std::fs::read_to_string(path.join(file))?;
                 match serde_json::from_str::<serde_json::Value>(&content) {
-                    Ok(_) => println!("   ✅ Valid JSON"),
-                    Err(e) => println!("   ❌ Invalid JSON: {}", e),
+                    Ok(value) => {
+                        if !json_output {
+                            println!("   ✅ Valid JSON");
+                        }
+                        if let Some(manifest_format) = ManifestFormat::from_filename(file) {
+                            match manifest_format.validate(&value) {
+                                Ok(errors) if errors.is_empty() => {
+                                    if !json_output {
+                                        println!("   ✅ Matches {} schema", file);
+                                    }
+                                }
+                                Ok(errors) => {
+                                    for error in errors {
+                                        if !json_output {
+                                            println!("   ❌ {error}");
+                                        }
+                                        schema_errors.push(format!("{file}: {error}"));
+                                    }
+                                }
+                                Err(e) => {
+                                    if !json_output {
+                                        println!("   ❌ Couldn't validate against schema: {e}");
+                                    }
+                                    schema_errors.push(format!("{file}: couldn't validate against schema: {e}"));
+                                }
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        if !json_output {
+                            println!("   ❌ Invalid JSON: {}", e);
+                        }
+                        schema_errors.push(format!("{file}: invalid JSON: {e}"));
+                    }
                 }
             }
         }
@@ -104,63 +435,54 @@ async fn validate_single_skill(path: PathBuf) -> Result<(), anyhow::Error> {
     // Check for README
     if path.join("README.md").exists() {
         has_readme = true;
-        println!("✅ Found README.md");
+        if !json_output {
+            println!("✅ Found README.md");
+        }
     }
 
     // Check for SKILL.md (Moltbot format)
-    if path.join("SKILL.md").exists() {
+    if path.join("SKILL.md").exists() && !json_output {
         println!("✅ Found SKILL.md");
     }
 
     // Check for .cursorrules (Cursor format)
     if path.join(".cursorrules").exists() {
         has_manifest = true;
-        println!("✅ Found .cursorrules");
+        if !json_output {
+            println!("✅ Found .cursorrules");
+        }
     }
 
-    println!();
+    if json_output {
+        let report = serde_json::json!({
+            "path": path.display().to_string(),
+            "has_manifest": has_manifest,
+            "has_readme": has_readme,
+            "schema_errors": schema_errors,
+        });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!();
 
-    if !has_manifest {
-        println!("⚠️  Warning: No recognized manifest file found");
-    }
+        if !has_manifest {
+            println!("⚠️  Warning: No recognized manifest file found");
+        }
 
-    if !has_readme {
-        println!("⚠️  Warning: No README.md found");
+        if !has_readme {
+            println!("⚠️  Warning: No README.md found");
+        }
     }
 
-    if has_manifest {
-        println!("\n✅ Skill structure appears valid");
-        Ok(())
-    } else {
+    if !has_manifest {
         anyhow::bail!("Skill is missing required manifest file");
     }
-}
 
-async fn validate_skill_structure(path: &PathBuf, source_type: &str) -> Result<(), anyhow::Error> {
-    if !path.exists() {
-        anyhow::bail!("Path does not exist");
-    }
-
-    // Check for appropriate files based on source type
-    let required_files: &[&str] = match source_type.to_lowercase().as_str() {
-        "claude" => &["claude.json", "skill.md", "README.md"],
-        "cline" => &["cline.json", "custom-instructions.md", "README.md"],
-        "cursor" => &[".cursorrules", "cursor.json", "README.md"],
-        "roocode" => &["roo.json", ".roomodes", "README.md"],
-        "moltbot" => &["manifest.json", "SKILL.md", "README.md"],
-        "openclaw" => &["skill.json", "README.md"],
-        "openaicodex" => &["codex.json", "instructions.md", "README.md"],
-        "kilocode" => &["kilo.yaml", "kilo.yml", "instructions.md", "README.md"],
-        _ => &["README.md"],
-    };
-
-    let has_required = required_files.iter().any(|file| path.join(file).exists());
+    if !schema_errors.is_empty() {
+        anyhow::bail!("{} schema validation error(s) found", schema_errors.len());
+    }
 
-    if !has_required {
-        anyhow::bail!(
-            "Missing required files. Expected one of: {:?}",
-            required_files
-        );
+    if !json_output {
+        println!("\n✅ Skill structure appears valid");
     }
 
     Ok(())
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 use alltheskills::{AllSkillsConfig, SkillScope, SourceType};
-use alltheskills::types::SourceConfig;
+use alltheskills::types::{CustomSourceConfig, SkillFormat, SourceConfig};
 
 const CONFIG_FILENAME: &str = "alltheskills.toml";
 
@@ -66,6 +66,19 @@ pub fn save_config(config: &AllSkillsConfig) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+/// Parses a `--manifest-format` value into the [`SkillFormat`] tagged onto
+/// skills discovered by a generically scanned custom source; an
+/// unrecognized name falls back to [`SkillFormat::Unknown`] rather than
+/// rejecting the command, matching `source_type`'s own fallback-to-`Custom`
+/// leniency below
+fn parse_manifest_format(format: &str) -> SkillFormat {
+    match format.to_lowercase().as_str() {
+        "json" | "generic-json" => SkillFormat::GenericJson,
+        "markdown" | "generic-markdown" => SkillFormat::GenericMarkdown,
+        _ => SkillFormat::Unknown,
+    }
+}
+
 /// Add a new source to the configuration
 pub fn add_source(
     config: &mut AllSkillsConfig,
@@ -73,23 +86,46 @@ pub fn add_source(
     path: &str,
     source_type: &str,
     scope: SkillScope,
+    pattern: Option<&str>,
+    manifest_filenames: Option<&str>,
+    manifest_format: Option<&str>,
 ) {
+    let source_type = match source_type.to_lowercase().as_str() {
+        "claude" => SourceType::Claude,
+        "cline" => SourceType::Cline,
+        "openclaw" => SourceType::Custom("openclaw".to_string()),
+        "roo" | "roocode" => SourceType::RooCode,
+        "codex" | "openai" => SourceType::OpenAICodex,
+        "kilo" => SourceType::KiloCode,
+        "github" => SourceType::GitHub,
+        "local" => SourceType::Local,
+        "oci" => SourceType::Oci,
+        _ => SourceType::Custom(source_type.to_string()),
+    };
+
+    // Only a `Custom` source with no dedicated provider needs its own
+    // manifest filenames/format -- they're ignored for built-in types,
+    // which already know their own layout.
+    let custom = match (&source_type, manifest_filenames) {
+        (SourceType::Custom(_), Some(filenames)) => Some(CustomSourceConfig {
+            manifest_filenames: filenames.split(',').map(|s| s.trim().to_string()).collect(),
+            format: manifest_format.map(parse_manifest_format).unwrap_or(SkillFormat::Unknown),
+        }),
+        _ => None,
+    };
+
     let source_config = SourceConfig {
         name: name.to_string(),
-        source_type: match source_type.to_lowercase().as_str() {
-            "claude" => SourceType::Claude,
-            "cline" => SourceType::Cline,
-            "openclaw" => SourceType::Custom("openclaw".to_string()),
-            "roo" | "roocode" => SourceType::RooCode,
-            "codex" | "openai" => SourceType::OpenAICodex,
-            "kilo" => SourceType::KiloCode,
-            "github" => SourceType::GitHub,
-            "local" => SourceType::Local,
-            _ => SourceType::Custom(source_type.to_string()),
-        },
+        source_type,
         enabled: true,
         scope,
         priority: config.sources.len() as i32,
+        github: None,
+        path: Some(PathBuf::from(path)),
+        pattern: pattern.map(str::to_string),
+        registry: None,
+        custom,
+        oci: None,
     };
     config.sources.push(source_config);
 }
@@ -0,0 +1,32 @@
+use alltheskills::types::DependencyLock;
+use std::path::{Path, PathBuf};
+
+const DEPS_LOCKFILE_FILENAME: &str = "skills.lock";
+
+/// Path to the dependency lockfile for a root skill installed under
+/// `target`, sitting alongside it the way `Cargo.lock` sits next to
+/// `Cargo.toml` -- distinct from `alltheskills.lock`
+/// ([`crate::lock`]), which pins every git-backed skill's commit
+/// globally rather than one root's resolved dependency graph.
+pub fn lock_path(target: &Path) -> PathBuf {
+    target.join(DEPS_LOCKFILE_FILENAME)
+}
+
+/// Loads the dependency lockfile at `path`, or `None` if it doesn't exist
+pub fn load_lock(path: &Path) -> Result<Option<DependencyLock>, anyhow::Error> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(path)?;
+    Ok(Some(toml::from_str(&content)?))
+}
+
+/// Writes `lock` to `path` as TOML, creating parent directories as needed
+pub fn save_lock(path: &Path, lock: &DependencyLock) -> Result<(), anyhow::Error> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let content = toml::to_string_pretty(lock)?;
+    std::fs::write(path, content)?;
+    Ok(())
+}
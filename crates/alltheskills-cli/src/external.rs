@@ -0,0 +1,158 @@
+//! Alias expansion and external subcommand dispatch
+//!
+//! Mirrors how `cargo` resolves both `[alias]` entries in its config and
+//! `cargo-<verb>` binaries on `PATH`: unknown subcommands aren't a hard
+//! error, they're a chance for a third-party binary (or a plugin, see
+//! [`alltheskills::providers::PluginProvider`]) to extend the CLI without
+//! this crate knowing about it in advance.
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use alltheskills::types::AliasValue;
+
+/// Maximum number of alias expansions before we assume a loop
+const MAX_ALIAS_DEPTH: usize = 8;
+
+/// Expands `args[0]` against the `[alias]` table, repeatedly.
+///
+/// `args` is the subcommand and its arguments (the binary name should
+/// already be stripped). Returns the expanded argument list; if the first
+/// token isn't an alias, or `args` is empty, it's returned unchanged.
+///
+/// Rejects recursive and self-referential aliases (`co = "co"`,
+/// `a = "b"` / `b = "a"`) outright rather than silently looping: the first
+/// time an alias name is seen a second time, expansion stops and the
+/// original, unexpanded arguments are returned so `clap` reports the
+/// mistyped/self-referential verb as an unknown subcommand.
+pub fn expand_aliases(args: Vec<String>, aliases: &HashMap<String, AliasValue>) -> Vec<String> {
+    let original = args.clone();
+    let mut args = args;
+    let mut seen = HashSet::new();
+
+    for _ in 0..MAX_ALIAS_DEPTH {
+        let Some(first) = args.first().cloned() else {
+            return args;
+        };
+
+        let Some(expansion) = aliases.get(&first) else {
+            return args;
+        };
+
+        if !seen.insert(first.clone()) {
+            eprintln!(
+                "alltheskills: alias '{first}' is recursive/self-referential, ignoring expansion"
+            );
+            return original;
+        }
+
+        let mut expanded = expansion.tokens();
+        expanded.extend(args.into_iter().skip(1));
+        args = expanded;
+    }
+
+    eprintln!("alltheskills: alias expansion did not terminate after {MAX_ALIAS_DEPTH} steps, giving up");
+    original
+}
+
+/// Looks up `alltheskills-<verb>` on `PATH`.
+pub fn find_external_subcommand(verb: &str) -> Option<PathBuf> {
+    let binary_name = format!("alltheskills-{verb}");
+    let path_var = std::env::var_os("PATH")?;
+
+    std::env::split_paths(&path_var)
+        .map(|dir| dir.join(&binary_name))
+        .find(|candidate| is_executable(candidate))
+}
+
+/// Runs the external subcommand `binary` with `args`, forwarding the
+/// current config path via `ALLTHESKILLS_CONFIG_PATH` so plugins can share
+/// configuration with the main binary. Returns the child's exit code.
+pub fn run_external(
+    binary: &PathBuf,
+    args: &[String],
+    config_path: &std::path::Path,
+) -> Result<i32, anyhow::Error> {
+    let status = std::process::Command::new(binary)
+        .args(args)
+        .env("ALLTHESKILLS_CONFIG_PATH", config_path)
+        .status()?;
+
+    Ok(status.code().unwrap_or(1))
+}
+
+fn is_executable(path: &PathBuf) -> bool {
+    if !path.is_file() {
+        return false;
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::metadata(path)
+            .map(|m| m.permissions().mode() & 0o111 != 0)
+            .unwrap_or(false)
+    }
+
+    #[cfg(not(unix))]
+    {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expands_single_alias() {
+        let mut aliases = HashMap::new();
+        aliases.insert("co".to_string(), AliasValue::Single("install --checkout".to_string()));
+
+        let expanded = expand_aliases(
+            vec!["co".to_string(), "foo".to_string()],
+            &aliases,
+        );
+
+        assert_eq!(expanded, vec!["install", "--checkout", "foo"]);
+    }
+
+    #[test]
+    fn expands_list_form_alias() {
+        let mut aliases = HashMap::new();
+        aliases.insert(
+            "ls".to_string(),
+            AliasValue::Multiple(vec!["list".to_string(), "--scope".to_string(), "user".to_string()]),
+        );
+
+        let expanded = expand_aliases(vec!["ls".to_string()], &aliases);
+
+        assert_eq!(expanded, vec!["list", "--scope", "user"]);
+    }
+
+    #[test]
+    fn leaves_non_aliases_untouched() {
+        let aliases = HashMap::new();
+        let args = vec!["list".to_string()];
+        assert_eq!(expand_aliases(args.clone(), &aliases), args);
+    }
+
+    #[test]
+    fn rejects_self_referential_alias() {
+        let mut aliases = HashMap::new();
+        aliases.insert("co".to_string(), AliasValue::Single("co".to_string()));
+
+        let expanded = expand_aliases(vec!["co".to_string()], &aliases);
+        assert_eq!(expanded, vec!["co".to_string()]);
+    }
+
+    #[test]
+    fn rejects_recursive_alias_cycle() {
+        let mut aliases = HashMap::new();
+        aliases.insert("a".to_string(), AliasValue::Single("b".to_string()));
+        aliases.insert("b".to_string(), AliasValue::Single("a".to_string()));
+
+        let expanded = expand_aliases(vec!["a".to_string()], &aliases);
+        assert_eq!(expanded, vec!["a".to_string()]);
+    }
+}
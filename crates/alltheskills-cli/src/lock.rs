@@ -0,0 +1,35 @@
+use alltheskills::types::Lockfile;
+use std::path::PathBuf;
+
+const LOCKFILE_FILENAME: &str = "alltheskills.lock";
+
+/// Get the lockfile path, next to `alltheskills.toml`
+pub fn get_lock_path() -> PathBuf {
+    crate::config::get_config_dir()
+        .join("alltheskills")
+        .join(LOCKFILE_FILENAME)
+}
+
+/// Load the lockfile, or an empty one if it doesn't exist yet
+pub fn load_lock() -> Result<Lockfile, anyhow::Error> {
+    let lock_path = get_lock_path();
+
+    if lock_path.exists() {
+        let content = std::fs::read_to_string(&lock_path)?;
+        let lock: Lockfile = toml::from_str(&content)?;
+        Ok(lock)
+    } else {
+        Ok(Lockfile::default())
+    }
+}
+
+/// Save the lockfile
+pub fn save_lock(lock: &Lockfile) -> Result<(), anyhow::Error> {
+    let lock_dir = crate::config::get_config_dir().join("alltheskills");
+    std::fs::create_dir_all(&lock_dir)?;
+
+    let content = toml::to_string_pretty(lock)?;
+    std::fs::write(lock_dir.join(LOCKFILE_FILENAME), content)?;
+
+    Ok(())
+}
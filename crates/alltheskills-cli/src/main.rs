@@ -1,7 +1,12 @@
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
+use std::path::Path;
 
 mod commands;
 mod config;
+mod deps_lock;
+mod external;
+mod lock;
+mod permissions;
 mod skill_exporter;
 
 #[derive(Parser)]
@@ -23,10 +28,24 @@ enum Commands {
     /// Install a skill from a source
     Install {
         /// Source URL or path (GitHub URL or local directory)
+        /// An `oci://<reference>` or a configured source alias name is also accepted
         source: String,
         /// Target directory for installation
         #[arg(short, long)]
         target: Option<String>,
+        /// Also resolve and install this skill's declared dependencies,
+        /// each into its own subdirectory of `target`
+        #[arg(long)]
+        with_deps: bool,
+        /// Also grant this capability's permissions to the skill, as if
+        /// `permission add <skill> <id>` had been run right after install
+        #[arg(long)]
+        capability: Option<String>,
+        /// Install even if the skill's manifest (or an attached
+        /// `--capability`) requests a wildcard-scoped permission; without
+        /// this flag, install is refused and nothing is granted
+        #[arg(long)]
+        allow_wildcard: bool,
     },
     /// Search for skills by name, description, or tags
     Search {
@@ -55,6 +74,20 @@ enum Commands {
         /// Scope for the source (global, user, project)
         #[arg(long, default_value = "user")]
         scope: String,
+        /// Glob pattern (`*`/`**`) applied under `path` to find skill
+        /// directories; defaults to scanning `path`'s immediate children
+        #[arg(long)]
+        pattern: Option<String>,
+        /// Comma-separated manifest filenames to look for in each skill
+        /// directory (e.g. "skill.json,*.yaml"), tried in order; only
+        /// consulted when `source_type` has no dedicated provider and no
+        /// code change is wanted for this source
+        #[arg(long)]
+        manifest_filenames: Option<String>,
+        /// Format to tag skills discovered by `manifest_filenames` with
+        /// ("json" or "markdown"); defaults to an unknown format
+        #[arg(long)]
+        manifest_format: Option<String>,
     },
     /// Remove a source from the configuration
     RemoveSource {
@@ -66,12 +99,153 @@ enum Commands {
         /// Show the config file path
         #[arg(short, long)]
         path: bool,
+        /// Print the resolved `[alias]` table instead of the rest of the config
+        #[arg(long)]
+        aliases: bool,
+    },
+    /// Lint installed skills, or validate a single skill directory
+    Validate {
+        /// Skill directory to validate; validates all installed skills if omitted
+        path: Option<String>,
+        /// Output format: "text" (default) or "json" for machine-readable findings
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+    /// Dump the JSON Schema for a recognized manifest format, for editor integration
+    Schema {
+        /// Manifest format to dump, e.g. "codex.json" or "Codex"
+        format: String,
+    },
+    /// Convert a skill to a different provider's on-disk format
+    Convert {
+        /// Skill name or ID to convert
+        name: String,
+        /// Target format (claude, cline, cursor, roo, openclaw, moltbot, codex,
+        /// kilo, vercel, cloudflare, markdown)
+        #[arg(short, long)]
+        to: String,
+        /// Output directory for the converted skill
+        #[arg(short, long)]
+        output_dir: Option<String>,
+    },
+    /// Check git-backed skills for updates and apply them
+    Update {
+        /// Skill name or ID to update; updates all skills if omitted
+        name: Option<String>,
+        /// Check out the commit recorded in `alltheskills.lock` instead of
+        /// fetching the latest commit on each skill's branch
+        #[arg(long)]
+        locked: bool,
+        /// Number of skills to update concurrently (defaults to the
+        /// `update_jobs` config value)
+        #[arg(long)]
+        jobs: Option<usize>,
+        /// Roll the named skill back to the commit it was pinned to
+        /// before its last update, instead of checking for new updates
+        #[arg(long)]
+        rollback: Option<String>,
+        /// Update a skill even if its on-disk tree no longer matches the
+        /// hash recorded in `alltheskills.lock`; without this flag,
+        /// drifted skills are left untouched
+        #[arg(long)]
+        allow_drift: bool,
+    },
+    /// Inspect and manage a skill's requested/granted permissions
+    Permission {
+        #[command(subcommand)]
+        action: PermissionCommands,
+    },
+    /// Define a named bundle of permissions
+    Capability {
+        #[command(subcommand)]
+        action: CapabilityCommands,
+    },
+    /// Generate shell completions for `alltheskills`, written to stdout
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: commands::CompletionShell,
+    },
+    /// Generate roff man pages for `alltheskills` and its subcommands
+    Man {
+        /// Directory to write one man page per subcommand into; prints
+        /// just the root page to stdout if omitted
+        #[arg(short, long)]
+        output_dir: Option<String>,
+    },
+    /// Anything else falls through to an `alltheskills-<verb>` binary on PATH
+    #[command(external_subcommand)]
+    External(Vec<String>),
+}
+
+#[derive(Subcommand)]
+enum PermissionCommands {
+    /// List a skill's requested permissions and which are granted, or
+    /// every permission/capability defined in the central config directory
+    /// if no skill is given
+    Ls {
+        /// Skill name or ID
+        skill: Option<String>,
+    },
+    /// Scaffold a new named, reusable permission definition
+    New {
+        /// Identifier the permission is referenced by
+        id: String,
+        /// Human-readable explanation of what this permission is for
+        #[arg(short, long, default_value = "")]
+        description: String,
+        /// Allow-list specs, e.g. `fsread:**/*.md`, `fswrite:out/*`,
+        /// `shell:git`, `net:api.github.com`
+        specs: Vec<String>,
+    },
+    /// Grant a skill every permission in a capability, prompting
+    /// interactively from the defined capabilities when no id is given
+    Add {
+        /// Skill ID; the grant is recorded under this string as given (a
+        /// skill name is not resolved to its ID)
+        skill: String,
+        /// Capability id, defined via `alltheskills capability new`
+        id: Option<String>,
+    },
+    /// Revoke a capability's permissions from a skill, prompting
+    /// interactively from the defined capabilities when no id is given
+    Rm {
+        /// Skill ID, matched against grants as given (a skill name is not
+        /// resolved to its ID)
+        skill: String,
+        /// Capability id to revoke
+        id: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum CapabilityCommands {
+    /// Define a new named bundle of permissions
+    New {
+        /// Identifier the capability is referenced by
+        id: String,
+        /// Scope this capability may be granted at (global, user, project)
+        #[arg(long)]
+        scope: Option<String>,
+        /// Permission specs, e.g. `tool:bash`, `fsread:**/*.md`,
+        /// `fswrite:out/*`, `net:api.github.com`, or `@<id>` referencing a
+        /// permission scaffolded with `alltheskills permission new`
+        permissions: Vec<String>,
     },
 }
 
 #[tokio::main]
 async fn main() -> Result<(), anyhow::Error> {
-    let args = Args::parse();
+    let cfg = config::load_config()?;
+
+    // Expand `[alias]` entries (e.g. `co = "install --checkout"`) before
+    // clap ever sees the arguments, the same way `git`/`cargo` aliases work.
+    let raw_args: Vec<String> = std::env::args().skip(1).collect();
+    let expanded = external::expand_aliases(raw_args, &cfg.aliases);
+
+    let mut full_args = vec!["alltheskills".to_string()];
+    full_args.extend(expanded);
+    let args = Args::parse_from(full_args);
 
     match args.command {
         Commands::List { scope } => {
@@ -83,8 +257,8 @@ async fn main() -> Result<(), anyhow::Error> {
             });
             commands::list_skills(scope).await?;
         }
-        Commands::Install { source, target } => {
-            commands::install_skill(&source, target.as_deref()).await?;
+        Commands::Install { source, target, with_deps, capability, allow_wildcard } => {
+            commands::install_skill(&source, target.as_deref(), with_deps, capability.as_deref(), allow_wildcard).await?;
         }
         Commands::Search { query } => {
             commands::search_skills(&query).await?;
@@ -100,6 +274,9 @@ async fn main() -> Result<(), anyhow::Error> {
             path,
             source_type,
             scope,
+            pattern,
+            manifest_filenames,
+            manifest_format,
         } => {
             let scope = match scope.to_lowercase().as_str() {
                 "global" => alltheskills::SkillScope::Global,
@@ -108,7 +285,16 @@ async fn main() -> Result<(), anyhow::Error> {
                 _ => alltheskills::SkillScope::User,
             };
             let mut config = config::load_config()?;
-            config::add_source(&mut config, &name, &path, &source_type, scope);
+            config::add_source(
+                &mut config,
+                &name,
+                &path,
+                &source_type,
+                scope,
+                pattern.as_deref(),
+                manifest_filenames.as_deref(),
+                manifest_format.as_deref(),
+            );
             config::save_config(&config)?;
             println!("Added source '{}' to configuration", name);
         }
@@ -121,9 +307,20 @@ async fn main() -> Result<(), anyhow::Error> {
                 println!("Source '{}' not found in configuration", name);
             }
         }
-        Commands::Config { path } => {
+        Commands::Config { path, aliases } => {
             if path {
                 println!("Config path: {}", config::get_config_path().display());
+            } else if aliases {
+                let config = config::load_config()?;
+                if config.aliases.is_empty() {
+                    println!("No aliases configured.");
+                } else {
+                    let mut names: Vec<&String> = config.aliases.keys().collect();
+                    names.sort();
+                    for name in names {
+                        println!("{name} = {}", config.aliases[name]);
+                    }
+                }
             } else {
                 let config = config::load_config()?;
                 println!("Current configuration:");
@@ -131,6 +328,7 @@ async fn main() -> Result<(), anyhow::Error> {
                 println!("  Default scope: {:?}", config.default_scope);
                 println!("  Install dir: {}", config.install_dir.display());
                 println!("  Cache dir: {}", config.cache_dir.display());
+                println!("  Update jobs: {}", config.update_jobs);
                 println!("  Sources:");
                 for source in &config.sources {
                     println!(
@@ -140,6 +338,88 @@ async fn main() -> Result<(), anyhow::Error> {
                 }
             }
         }
+        Commands::Validate { path, format } => {
+            commands::validate_skill(path.as_deref(), &format).await?;
+        }
+        Commands::Schema { format } => {
+            commands::print_schema(&format)?;
+        }
+        Commands::Convert { name, to, output_dir } => {
+            commands::convert_skill(&name, &to, output_dir.as_deref()).await?;
+        }
+        Commands::Update { name, locked, jobs, rollback, allow_drift } => {
+            if let Some(rollback_name) = rollback {
+                commands::rollback_skill(&rollback_name).await?;
+            } else {
+                let jobs = jobs.unwrap_or(cfg.update_jobs);
+                commands::update_skill(name.as_deref(), locked, jobs, allow_drift).await?;
+            }
+        }
+        Commands::Permission { action } => match action {
+            PermissionCommands::Ls { skill } => commands::permission_ls(skill.as_deref()).await?,
+            PermissionCommands::New { id, description, specs } => {
+                commands::permission_new(&id, &description, &specs)?
+            }
+            PermissionCommands::Add { skill, id } => commands::permission_add(&skill, id.as_deref())?,
+            PermissionCommands::Rm { skill, id } => commands::permission_rm(&skill, id.as_deref())?,
+        },
+        Commands::Capability { action } => match action {
+            CapabilityCommands::New { id, scope, permissions } => {
+                let scope = match scope.as_deref().map(str::to_lowercase).as_deref() {
+                    Some("global") => Some(alltheskills::SkillScope::Global),
+                    Some("user") => Some(alltheskills::SkillScope::User),
+                    Some("project") => Some(alltheskills::SkillScope::Project),
+                    Some(other) => anyhow::bail!("unknown scope '{other}', expected global, user, or project"),
+                    None => None,
+                };
+                commands::capability_new(&id, scope, &permissions)?
+            }
+        },
+        Commands::Completions { shell } => {
+            let mut cmd = Args::command();
+            commands::write_completions(&mut cmd, shell, &mut std::io::stdout());
+        }
+        Commands::Man { output_dir } => {
+            let cmd = Args::command();
+            commands::write_man_pages(&cmd, output_dir.as_deref().map(Path::new))?;
+        }
+        Commands::External(verb_and_args) => {
+            let Some((verb, rest)) = verb_and_args.split_first() else {
+                anyhow::bail!("no subcommand given");
+            };
+
+            match external::find_external_subcommand(verb) {
+                Some(binary) => {
+                    let code = external::run_external(&binary, rest, &config::get_config_path())?;
+                    std::process::exit(code);
+                }
+                None => {
+                    const BUILTIN_VERBS: &[&str] = &[
+                        "list",
+                        "install",
+                        "search",
+                        "info",
+                        "export-as-skill",
+                        "add-source",
+                        "remove-source",
+                        "config",
+                        "validate",
+                        "convert",
+                        "update",
+                        "completions",
+                        "man",
+                    ];
+                    let suggestions = alltheskills::utils::suggest_closest(verb, BUILTIN_VERBS, 3);
+
+                    let mut message =
+                        format!("no such subcommand: `{verb}`\n\nTried built-ins and looked for `alltheskills-{verb}` on PATH.");
+                    if !suggestions.is_empty() {
+                        message.push_str(&format!("\n\nDid you mean: {}?", suggestions.join(", ")));
+                    }
+                    anyhow::bail!(message);
+                }
+            }
+        }
     }
 
     Ok(())
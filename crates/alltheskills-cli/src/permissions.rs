@@ -0,0 +1,116 @@
+//! Permission/capability/grant storage
+//!
+//! All three stores are flat TOML files under a single centralized CLI
+//! config directory (`crate::config::get_config_dir().join("alltheskills")`),
+//! the same directory `alltheskills.toml` itself lives in -- not one
+//! `permissions/` directory per skill source. A grant is keyed by skill id
+//! directly, so it's independent of which source a skill was installed
+//! from.
+
+use alltheskills::types::{Capability, PermissionDef, PermissionGrant};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+const PERMISSIONS_FILENAME: &str = "alltheskills.permissions";
+const CAPABILITIES_FILENAME: &str = "alltheskills.capabilities";
+const GRANTS_FILENAME: &str = "alltheskills.grants";
+
+/// Every permission defined via `permission new`, keyed by id
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct PermissionStore {
+    #[serde(default)]
+    pub permissions: HashMap<String, PermissionDef>,
+}
+
+/// Every capability defined via `capability new`, keyed by id
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct CapabilityStore {
+    #[serde(default)]
+    pub capabilities: HashMap<String, Capability>,
+}
+
+/// Every skill's consented permissions, keyed by skill id
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct GrantStore {
+    #[serde(default)]
+    pub grants: HashMap<String, PermissionGrant>,
+}
+
+fn get_permissions_path() -> PathBuf {
+    crate::config::get_config_dir()
+        .join("alltheskills")
+        .join(PERMISSIONS_FILENAME)
+}
+
+fn get_capabilities_path() -> PathBuf {
+    crate::config::get_config_dir()
+        .join("alltheskills")
+        .join(CAPABILITIES_FILENAME)
+}
+
+fn get_grants_path() -> PathBuf {
+    crate::config::get_config_dir()
+        .join("alltheskills")
+        .join(GRANTS_FILENAME)
+}
+
+/// Load every defined permission, or an empty store if none exist yet
+pub fn load_permissions() -> Result<PermissionStore, anyhow::Error> {
+    let path = get_permissions_path();
+    if path.exists() {
+        let content = std::fs::read_to_string(&path)?;
+        Ok(toml::from_str(&content)?)
+    } else {
+        Ok(PermissionStore::default())
+    }
+}
+
+/// Save the permission store
+pub fn save_permissions(store: &PermissionStore) -> Result<(), anyhow::Error> {
+    let dir = crate::config::get_config_dir().join("alltheskills");
+    std::fs::create_dir_all(&dir)?;
+    let content = toml::to_string_pretty(store)?;
+    std::fs::write(dir.join(PERMISSIONS_FILENAME), content)?;
+    Ok(())
+}
+
+/// Load every defined capability, or an empty store if none exist yet
+pub fn load_capabilities() -> Result<CapabilityStore, anyhow::Error> {
+    let path = get_capabilities_path();
+    if path.exists() {
+        let content = std::fs::read_to_string(&path)?;
+        Ok(toml::from_str(&content)?)
+    } else {
+        Ok(CapabilityStore::default())
+    }
+}
+
+/// Save the capability store
+pub fn save_capabilities(store: &CapabilityStore) -> Result<(), anyhow::Error> {
+    let dir = crate::config::get_config_dir().join("alltheskills");
+    std::fs::create_dir_all(&dir)?;
+    let content = toml::to_string_pretty(store)?;
+    std::fs::write(dir.join(CAPABILITIES_FILENAME), content)?;
+    Ok(())
+}
+
+/// Load every skill's granted permissions, or an empty store if none
+/// have been granted yet
+pub fn load_grants() -> Result<GrantStore, anyhow::Error> {
+    let path = get_grants_path();
+    if path.exists() {
+        let content = std::fs::read_to_string(&path)?;
+        Ok(toml::from_str(&content)?)
+    } else {
+        Ok(GrantStore::default())
+    }
+}
+
+/// Save the grant store
+pub fn save_grants(store: &GrantStore) -> Result<(), anyhow::Error> {
+    let dir = crate::config::get_config_dir().join("alltheskills");
+    std::fs::create_dir_all(&dir)?;
+    let content = toml::to_string_pretty(store)?;
+    std::fs::write(dir.join(GRANTS_FILENAME), content)?;
+    Ok(())
+}
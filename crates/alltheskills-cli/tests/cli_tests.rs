@@ -83,3 +83,38 @@ fn test_cli_update() {
     cmd.assert()
         .success();
 }
+
+/// Test that `completions bash` includes every top-level subcommand name
+#[test]
+fn test_cli_completions_bash_contains_subcommands() {
+    let mut cmd = Command::cargo_bin("alltheskills").unwrap();
+    cmd.arg("completions").arg("bash");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("list"))
+        .stdout(predicate::str::contains("install"))
+        .stdout(predicate::str::contains("search"))
+        .stdout(predicate::str::contains("info"))
+        .stdout(predicate::str::contains("export-as-skill"))
+        .stdout(predicate::str::contains("add-source"))
+        .stdout(predicate::str::contains("remove-source"))
+        .stdout(predicate::str::contains("config"))
+        .stdout(predicate::str::contains("validate"))
+        .stdout(predicate::str::contains("convert"))
+        .stdout(predicate::str::contains("update"))
+        .stdout(predicate::str::contains("schema"))
+        .stdout(predicate::str::contains("permission"))
+        .stdout(predicate::str::contains("capability"))
+        .stdout(predicate::str::contains("completions"))
+        .stdout(predicate::str::contains("man"));
+}
+
+/// Test that `man` with no output dir prints the root roff page to stdout
+#[test]
+fn test_cli_man_stdout() {
+    let mut cmd = Command::cargo_bin("alltheskills").unwrap();
+    cmd.arg("man");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(".TH"));
+}
@@ -0,0 +1,133 @@
+//! SQLite-backed cache of discovered skills
+//!
+//! [`SkillReader::list_all_skills`](crate::SkillReader::list_all_skills)
+//! re-scans every configured provider on each call, which is slow for
+//! remote sources like a GitHub organization. `SkillCache` persists the
+//! last scan of each source under [`AllSkillsConfig::cache_dir`](crate::types::AllSkillsConfig::cache_dir),
+//! keyed by [`SourceConfig::name`](crate::types::SourceConfig::name), so a
+//! `SkillReader` can skip a provider's `list_skills` call entirely when its
+//! [`SkillProvider::scan_key`](crate::providers::SkillProvider::scan_key)
+//! (a directory mtime, a git HEAD SHA, ...) hasn't changed.
+//!
+//! Keying by the source's own name rather than its bare [`SourceType`] is
+//! deliberate: `SourceType` variants like `SourceType::GitHub` are shared by
+//! every `SourceConfig` of that type, so two configured GitHub sources (two
+//! different orgs, say) would otherwise collide on the same cache row.
+
+use rusqlite::{params, Connection, OptionalExtension};
+use std::path::Path;
+
+use crate::types::Skill;
+use crate::Result;
+
+fn db_error(source: rusqlite::Error) -> crate::Error {
+    crate::Error::Config { message: format!("cache error: {source}") }
+}
+
+/// A SQLite-backed cache of per-source skill scans
+pub struct SkillCache {
+    conn: Connection,
+}
+
+impl SkillCache {
+    /// Opens (creating if necessary) the cache database under `cache_dir`
+    pub fn open(cache_dir: &Path) -> Result<Self> {
+        std::fs::create_dir_all(cache_dir)?;
+        let conn = Connection::open(cache_dir.join("skills.sqlite3")).map_err(db_error)?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS skills (
+                source_name TEXT NOT NULL,
+                id TEXT NOT NULL,
+                data TEXT NOT NULL,
+                content_hash TEXT NOT NULL,
+                last_seen INTEGER NOT NULL,
+                PRIMARY KEY (source_name, id)
+            );
+            CREATE TABLE IF NOT EXISTS sources (
+                source_name TEXT PRIMARY KEY,
+                scan_key TEXT,
+                last_scanned INTEGER NOT NULL
+            );",
+        )
+        .map_err(db_error)?;
+        Ok(Self { conn })
+    }
+
+    /// Returns the scan key `source_name` was last cached under, if any
+    pub fn scan_key(&self, source_name: &str) -> Result<Option<String>> {
+        self.conn
+            .query_row(
+                "SELECT scan_key FROM sources WHERE source_name = ?1",
+                params![source_name],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(db_error)
+    }
+
+    /// Returns every cached skill for `source_name`
+    pub fn skills_for_source(&self, source_name: &str) -> Result<Vec<Skill>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT data FROM skills WHERE source_name = ?1")
+            .map_err(db_error)?;
+        let rows = stmt
+            .query_map(params![source_name], |row| row.get::<_, String>(0))
+            .map_err(db_error)?;
+
+        let mut skills = Vec::new();
+        for row in rows {
+            skills.push(serde_json::from_str(&row.map_err(db_error)?)?);
+        }
+        Ok(skills)
+    }
+
+    /// Replaces every cached skill for `source_name` with `skills` and
+    /// records `scan_key` as the condition under which they stay fresh
+    pub fn upsert_source(&mut self, source_name: &str, scan_key: Option<&str>, skills: &[Skill]) -> Result<()> {
+        let now = chrono::Utc::now().timestamp();
+
+        let tx = self.conn.transaction().map_err(db_error)?;
+        tx.execute("DELETE FROM skills WHERE source_name = ?1", params![source_name])
+            .map_err(db_error)?;
+        for skill in skills {
+            let data = serde_json::to_string(skill)?;
+            let content_hash = content_hash(&data);
+            tx.execute(
+                "INSERT INTO skills (source_name, id, data, content_hash, last_seen)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![source_name, skill.id, data, content_hash, now],
+            )
+            .map_err(db_error)?;
+        }
+        tx.execute(
+            "INSERT INTO sources (source_name, scan_key, last_scanned) VALUES (?1, ?2, ?3)
+             ON CONFLICT(source_name) DO UPDATE SET scan_key = excluded.scan_key, last_scanned = excluded.last_scanned",
+            params![source_name, scan_key, now],
+        )
+        .map_err(db_error)?;
+        tx.commit().map_err(db_error)
+    }
+
+    /// Drops every cached row for `source_name`
+    pub fn invalidate(&mut self, source_name: &str) -> Result<()> {
+        self.conn
+            .execute("DELETE FROM skills WHERE source_name = ?1", params![source_name])
+            .map_err(db_error)?;
+        self.conn
+            .execute("DELETE FROM sources WHERE source_name = ?1", params![source_name])
+            .map_err(db_error)?;
+        Ok(())
+    }
+}
+
+/// Deterministic, non-cryptographic content hash over `data`, the same
+/// `DefaultHasher` approach as [`crate::core::content_hash`]
+fn content_hash(data: &str) -> String {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    data.hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
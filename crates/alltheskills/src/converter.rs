@@ -0,0 +1,268 @@
+//! Cross-format skill conversion
+//!
+//! [`SkillFormat`] already distinguishes the on-disk layout every provider
+//! understands (`RooSkill`, `ClaudeSkill`, `CursorRules`, ...). This module
+//! takes a [`Skill`] that one provider parsed, plus its raw content body,
+//! and writes it back out in a *different* format's layout so a skill
+//! library can be carried across assistants instead of copied by hand.
+//!
+//! Each target gets a manifest file (JSON, YAML, TOML, or none) and a
+//! `README.md`/content file; [`SkillMetadata`](crate::types::SkillMetadata)
+//! fields are mapped onto whichever of those the target recognizes. Targets
+//! whose manifest has fields with no source-side equivalent (Moltbot
+//! `commands`, Cloudflare `wrangler.toml` bindings, ...) report those gaps
+//! as warning strings rather than failing the conversion.
+
+use std::path::Path;
+
+use crate::types::Skill;
+use crate::{Error, Result};
+
+/// A provider layout [`convert_skill`] can render into.
+///
+/// This mirrors the set of layouts `alltheskills init` can scaffold, rather
+/// than [`SkillFormat`](crate::types::SkillFormat) directly, since a couple
+/// of them (`Vercel`, `Cloudflare`) share a `SkillFormat::GenericJson`
+/// parse path but need distinct manifests on the way out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvertTarget {
+    /// `claude.json` + `skill.md`
+    Claude,
+    /// `cline.json` + `custom-instructions.md`
+    Cline,
+    /// `.cursorrules` + `cursor.json`
+    Cursor,
+    /// `roo.json`
+    Roo,
+    /// `skill.json` (OpenClaw)
+    OpenClaw,
+    /// `manifest.json` + `SKILL.md`
+    Moltbot,
+    /// `codex.json` + `instructions.md`
+    Codex,
+    /// `kilo.yaml` + `instructions.md`
+    Kilo,
+    /// `skill.json` + `ai.config.json` (Vercel AI SDK)
+    Vercel,
+    /// `wrangler.toml` (Cloudflare Workers AI)
+    Cloudflare,
+    /// `README.md` only
+    GenericMarkdown,
+}
+
+/// Converts `skill` (with its raw `content` body, as returned by
+/// [`SkillProvider::read_skill`](crate::providers::SkillProvider::read_skill))
+/// into `target`'s on-disk layout under `output_dir`.
+///
+/// Returns warnings (not errors) for manifest fields `target` expects that
+/// have no equivalent on `skill` — for example converting to Moltbot when
+/// the source has no `cmd:`-tagged commands, or to Cloudflare when there's
+/// no binding information to put in `wrangler.toml`.
+pub fn convert_skill(
+    skill: &Skill,
+    content: &str,
+    target: &ConvertTarget,
+    output_dir: &Path,
+) -> Result<Vec<String>> {
+    std::fs::create_dir_all(output_dir)?;
+
+    match target {
+        ConvertTarget::Claude => write_claude(skill, content, output_dir),
+        ConvertTarget::Cline => write_cline(skill, content, output_dir),
+        ConvertTarget::Cursor => write_cursor(skill, content, output_dir),
+        ConvertTarget::Roo => write_roo(skill, content, output_dir),
+        ConvertTarget::OpenClaw => write_openclaw(skill, content, output_dir),
+        ConvertTarget::Moltbot => write_moltbot(skill, content, output_dir),
+        ConvertTarget::Codex => write_codex(skill, content, output_dir),
+        ConvertTarget::Kilo => write_kilo(skill, content, output_dir),
+        ConvertTarget::Vercel => write_vercel(skill, content, output_dir),
+        ConvertTarget::Cloudflare => write_cloudflare(skill, content, output_dir),
+        ConvertTarget::GenericMarkdown => write_generic_markdown(skill, content, output_dir),
+    }
+}
+
+fn metadata_json(skill: &Skill) -> serde_json::Value {
+    serde_json::json!({
+        "name": skill.name,
+        "description": skill.description,
+        "version": skill.version,
+        "author": skill.metadata.author,
+        "tags": skill.metadata.tags,
+    })
+}
+
+fn write_readme(skill: &Skill, content: &str, output_dir: &Path) -> Result<()> {
+    let readme = format!("# {}\n\n{}\n\n{}\n", skill.name, skill.description, content);
+    std::fs::write(output_dir.join("README.md"), readme)?;
+    Ok(())
+}
+
+fn write_claude(skill: &Skill, content: &str, output_dir: &Path) -> Result<Vec<String>> {
+    std::fs::write(
+        output_dir.join("claude.json"),
+        serde_json::to_string_pretty(&metadata_json(skill))?,
+    )?;
+    std::fs::write(output_dir.join("skill.md"), content)?;
+    write_readme(skill, content, output_dir)?;
+    Ok(Vec::new())
+}
+
+fn write_cline(skill: &Skill, content: &str, output_dir: &Path) -> Result<Vec<String>> {
+    std::fs::write(
+        output_dir.join("cline.json"),
+        serde_json::to_string_pretty(&metadata_json(skill))?,
+    )?;
+    std::fs::write(output_dir.join("custom-instructions.md"), content)?;
+    write_readme(skill, content, output_dir)?;
+    Ok(Vec::new())
+}
+
+fn write_cursor(skill: &Skill, content: &str, output_dir: &Path) -> Result<Vec<String>> {
+    std::fs::write(output_dir.join(".cursorrules"), content)?;
+    std::fs::write(
+        output_dir.join("cursor.json"),
+        serde_json::to_string_pretty(&metadata_json(skill))?,
+    )?;
+    write_readme(skill, content, output_dir)?;
+    Ok(Vec::new())
+}
+
+fn write_roo(skill: &Skill, content: &str, output_dir: &Path) -> Result<Vec<String>> {
+    std::fs::write(
+        output_dir.join("roo.json"),
+        serde_json::to_string_pretty(&metadata_json(skill))?,
+    )?;
+    write_readme(skill, content, output_dir)?;
+    Ok(Vec::new())
+}
+
+fn write_openclaw(skill: &Skill, content: &str, output_dir: &Path) -> Result<Vec<String>> {
+    std::fs::write(
+        output_dir.join("skill.json"),
+        serde_json::to_string_pretty(&metadata_json(skill))?,
+    )?;
+    write_readme(skill, content, output_dir)?;
+    Ok(Vec::new())
+}
+
+fn write_moltbot(skill: &Skill, content: &str, output_dir: &Path) -> Result<Vec<String>> {
+    let mut warnings = Vec::new();
+
+    let commands: Vec<&str> = skill
+        .metadata
+        .tags
+        .iter()
+        .filter_map(|t| t.strip_prefix("cmd:"))
+        .collect();
+
+    if commands.is_empty() {
+        warnings.push(
+            "target expects a `commands` array in manifest.json, but the source skill has no \
+             `cmd:`-tagged commands to carry over; writing an empty list"
+                .to_string(),
+        );
+    }
+
+    let mut manifest = metadata_json(skill);
+    manifest["commands"] = serde_json::Value::Array(
+        commands
+            .into_iter()
+            .map(|name| serde_json::json!({ "name": name }))
+            .collect(),
+    );
+
+    std::fs::write(
+        output_dir.join("manifest.json"),
+        serde_json::to_string_pretty(&manifest)?,
+    )?;
+    std::fs::write(output_dir.join("SKILL.md"), content)?;
+    write_readme(skill, content, output_dir)?;
+    Ok(warnings)
+}
+
+fn write_codex(skill: &Skill, content: &str, output_dir: &Path) -> Result<Vec<String>> {
+    let warnings = vec![
+        "target's `model` field has no source-side equivalent; leaving it unset".to_string(),
+    ];
+
+    std::fs::write(
+        output_dir.join("codex.json"),
+        serde_json::to_string_pretty(&metadata_json(skill))?,
+    )?;
+    std::fs::write(output_dir.join("instructions.md"), content)?;
+    write_readme(skill, content, output_dir)?;
+    Ok(warnings)
+}
+
+fn write_kilo(skill: &Skill, content: &str, output_dir: &Path) -> Result<Vec<String>> {
+    let kilo_yaml = serde_yaml::to_string(&metadata_json(skill)).map_err(|e| Error::Parse {
+        message: format!("Failed to serialize kilo.yaml: {e}"),
+    })?;
+    std::fs::write(output_dir.join("kilo.yaml"), kilo_yaml)?;
+    std::fs::write(output_dir.join("instructions.md"), content)?;
+    write_readme(skill, content, output_dir)?;
+    Ok(Vec::new())
+}
+
+fn write_vercel(skill: &Skill, content: &str, output_dir: &Path) -> Result<Vec<String>> {
+    let mut manifest = metadata_json(skill);
+    manifest["id"] = serde_json::Value::String(skill.id.clone());
+    manifest["repository"] = skill
+        .metadata
+        .repository
+        .clone()
+        .map(serde_json::Value::String)
+        .unwrap_or(serde_json::Value::Null);
+
+    std::fs::write(
+        output_dir.join("skill.json"),
+        serde_json::to_string_pretty(&manifest)?,
+    )?;
+    std::fs::write(
+        output_dir.join("ai.config.json"),
+        serde_json::to_string_pretty(&serde_json::json!({
+            "model": "openai/gpt-4o",
+            "systemPrompt": content,
+        }))?,
+    )?;
+    write_readme(skill, content, output_dir)?;
+    Ok(Vec::new())
+}
+
+/// Minimal `wrangler.toml` shape this converter writes -- serialized via
+/// `toml` rather than hand-formatted, so a skill's `id`/`description`
+/// (free text from a possibly untrusted registry or GitHub repo) can't
+/// inject extra TOML keys or break the file with an unescaped `"`
+#[derive(serde::Serialize)]
+struct WranglerToml {
+    name: String,
+    main: String,
+    compatibility_date: String,
+    description: String,
+}
+
+fn write_cloudflare(skill: &Skill, content: &str, output_dir: &Path) -> Result<Vec<String>> {
+    let warnings = vec![
+        "target's `wrangler.toml` bindings (e.g. the `[ai]` AI binding) have no source-side \
+         equivalent; the generated config has no bindings and will need to be completed by hand"
+            .to_string(),
+    ];
+
+    let wrangler = WranglerToml {
+        name: skill.id.clone(),
+        main: "src/index.ts".to_string(),
+        compatibility_date: "2024-01-01".to_string(),
+        description: skill.description.clone(),
+    };
+    let wrangler_toml = toml::to_string_pretty(&wrangler).map_err(|e| Error::Config {
+        message: format!("failed to serialize wrangler.toml: {e}"),
+    })?;
+    std::fs::write(output_dir.join("wrangler.toml"), wrangler_toml)?;
+    write_readme(skill, content, output_dir)?;
+    Ok(warnings)
+}
+
+fn write_generic_markdown(skill: &Skill, content: &str, output_dir: &Path) -> Result<Vec<String>> {
+    write_readme(skill, content, output_dir)?;
+    Ok(Vec::new())
+}
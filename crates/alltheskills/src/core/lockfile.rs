@@ -0,0 +1,44 @@
+//! Lockfile I/O and commit pinning
+//!
+//! A thin, explicit-path counterpart to the CLI's own `alltheskills.lock`
+//! handling (`alltheskills-cli/src/lock.rs`), usable from
+//! [`SkillReader::sync_locked`](crate::SkillReader::sync_locked) without
+//! the library needing to know where the CLI keeps its config directory.
+
+use std::path::Path;
+
+use crate::types::Lockfile;
+use crate::{Error, Result};
+
+/// Reads `path` as a [`Lockfile`], or returns an empty one if it doesn't
+/// exist yet
+pub fn load_lock(path: &Path) -> Result<Lockfile> {
+    if !path.exists() {
+        return Ok(Lockfile::default());
+    }
+    let content = std::fs::read_to_string(path)?;
+    toml::from_str(&content).map_err(|e| Error::Config {
+        message: format!("invalid lockfile at {}: {e}", path.display()),
+    })
+}
+
+/// Writes `lock` to `path` as TOML, creating parent directories as needed
+pub fn save_lock(path: &Path, lock: &Lockfile) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let content = toml::to_string_pretty(lock).map_err(|e| Error::Config {
+        message: format!("failed to serialize lockfile: {e}"),
+    })?;
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+/// Hard-resets the git repository at `path` to `commit`, without fetching
+pub fn checkout_commit(path: &Path, commit: &str) -> Result<()> {
+    let repo = git2::Repository::open(path)?;
+    let oid = git2::Oid::from_str(commit)?;
+    let object = repo.find_object(oid, None)?;
+    repo.reset(&object, git2::ResetType::Hard, None)?;
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//! Reproducibility primitives shared by the CLI's install/update flows
+//!
+//! Houses lockfile I/O and two content-hash helpers so a caller recording
+//! or verifying a [`Lockfile`](crate::types::Lockfile) entry doesn't need
+//! to reimplement TOML (de)serialization, commit-pinning, or tree hashing
+//! itself: [`content_hash`] is a cheap metadata-string fingerprint,
+//! [`hash_tree`] is a SHA-256 digest of the actual files on disk.
+
+pub mod lockfile;
+pub mod retry;
+pub mod tree_hash;
+
+pub use lockfile::{checkout_commit, load_lock, save_lock};
+pub use retry::{with_retry, RetryConfig};
+pub use tree_hash::hash_tree;
+
+/// Deterministic, non-cryptographic fingerprint over `parts`, joined in order
+///
+/// A cheap way to notice that some combination of metadata strings (an
+/// id, a version, a resolved ref, ...) changed, without caring what the
+/// strings actually are -- [`LockedSkill::content_hash`](crate::types::LockedSkill::content_hash)
+/// used to be built this way, but now uses [`hash_tree`] instead, since a
+/// metadata fingerprint can't tell a caller whether the files themselves
+/// changed.
+pub fn content_hash(parts: &[&str]) -> String {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    for part in parts {
+        part.hash(&mut hasher);
+    }
+    format!("{:x}", hasher.finish())
+}
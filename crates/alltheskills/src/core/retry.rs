@@ -0,0 +1,116 @@
+//! Retry with exponential backoff for network-backed operations
+//!
+//! Wraps a single [`SkillProvider::install`](crate::providers::SkillProvider::install)
+//! or [`list_skills`](crate::providers::SkillProvider::list_skills) call
+//! (or the GitHub API requests underneath them) so a flaky network or a
+//! transient rate limit doesn't abort an org-wide discovery or an
+//! install outright. Auth and not-found errors are never retried -- only
+//! errors [`is_transient`] recognizes as likely to succeed on a later
+//! attempt.
+
+use std::future::Future;
+use std::time::Duration;
+
+use crate::types::AllSkillsConfig;
+use crate::{Error, Result};
+
+/// Longest delay [`with_retry`] will ever wait between attempts,
+/// regardless of how many attempts have already been made
+const MAX_DELAY_MS: u64 = 10_000;
+
+/// Knobs controlling [`with_retry`], sourced from [`AllSkillsConfig`]
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    /// Maximum attempts, including the first, before giving up
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubles each subsequent attempt
+    pub base_delay_ms: u64,
+}
+
+impl RetryConfig {
+    /// Builds a [`RetryConfig`] from the `retry_attempts`/
+    /// `retry_base_delay_ms` knobs in `config`
+    pub fn from_config(config: &AllSkillsConfig) -> Self {
+        Self {
+            max_attempts: config.retry_attempts,
+            base_delay_ms: config.retry_base_delay_ms,
+        }
+    }
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self::from_config(&AllSkillsConfig::default())
+    }
+}
+
+/// Runs `operation`, retrying with exponential backoff and jitter on a
+/// transient [`Error`] until `config.max_attempts` is reached
+///
+/// Non-transient errors (auth failures, not-found, parse errors) are
+/// returned immediately without retrying.
+pub async fn with_retry<T, F, Fut>(config: RetryConfig, mut operation: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt < config.max_attempts.max(1) && is_transient(&e) => {
+                tokio::time::sleep(backoff_delay(config.base_delay_ms, attempt)).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Whether `error` is the kind of failure a later attempt is likely to
+/// resolve on its own: network-level git transport errors, HTTP 429/5xx,
+/// and I/O errors, but not auth failures, missing resources, or
+/// malformed data
+fn is_transient(error: &Error) -> bool {
+    match error {
+        Error::Git { source } => {
+            matches!(
+                source.class(),
+                git2::ErrorClass::Net | git2::ErrorClass::Os | git2::ErrorClass::Ssh | git2::ErrorClass::Http
+            ) && !matches!(source.code(), git2::ErrorCode::Auth | git2::ErrorCode::NotFound)
+        }
+        Error::Http { status: Some(status), .. } => *status == 429 || *status >= 500,
+        Error::Http { status: None, .. } => true,
+        Error::Io { source } => source.kind() != std::io::ErrorKind::NotFound,
+        _ => false,
+    }
+}
+
+/// Delay before `attempt`'s retry: `base_delay_ms * 2^(attempt - 1)`,
+/// capped at [`MAX_DELAY_MS`] and jittered by up to +/-25% so many
+/// concurrent retries don't all wake up at once
+fn backoff_delay(base_delay_ms: u64, attempt: u32) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(16);
+    let delay_ms = base_delay_ms.saturating_mul(1u64 << exponent).min(MAX_DELAY_MS);
+
+    let jitter_range = delay_ms / 4;
+    let jitter = if jitter_range == 0 {
+        0
+    } else {
+        (jitter_seed(attempt) % (jitter_range * 2 + 1)) as i64 - jitter_range as i64
+    };
+    let jittered = ((delay_ms as i64 + jitter).max(0) as u64).min(MAX_DELAY_MS);
+
+    Duration::from_millis(jittered)
+}
+
+/// Per-attempt jitter source that hashes the attempt number together with
+/// the current instant, since the crate avoids a dependency on a random
+/// number generator for something this small
+fn jitter_seed(attempt: u32) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    attempt.hash(&mut hasher);
+    std::time::Instant::now().hash(&mut hasher);
+    hasher.finish()
+}
@@ -0,0 +1,68 @@
+//! SHA-256 hash over an installed skill's directory tree
+//!
+//! [`content_hash`](super::content_hash) hashes a handful of metadata
+//! strings (skill id, version, resolved commit) -- cheap, but it can't
+//! tell a reviewer whether the files actually on disk still match what
+//! was installed, only whether the *identifiers* still match. [`hash_tree`]
+//! hashes the tree's actual bytes, so [`SkillProvider::verify`](crate::providers::SkillProvider::verify)
+//! can detect tampering or drift even when the commit/version recorded in
+//! `alltheskills.lock` hasn't changed.
+
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+
+use crate::Result;
+
+/// Hashes every regular file under `root`, in a deterministic order, into
+/// one SHA-256 digest
+///
+/// Each file's path (relative to `root`, with platform-independent `/`
+/// separators) and contents are fed into the hasher in sorted-path order,
+/// so the result doesn't depend on directory-read order and two identical
+/// trees hash identically regardless of the platform they were walked on.
+/// `.git` is skipped -- its own tree already changes per-commit, and
+/// hashing it would make every clone of the same commit hash differently
+/// (loose objects, packfiles, and reflogs aren't deterministic across
+/// clones).
+pub fn hash_tree(root: &Path) -> Result<String> {
+    let mut files = collect_files(root, root)?;
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for relative in &files {
+        hasher.update(relative.as_bytes());
+        let content = std::fs::read(root.join(relative))?;
+        hasher.update(&content);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Recursively collects every regular file under `dir`, as paths relative
+/// to `root` with `/` separators
+fn collect_files(root: &Path, dir: &Path) -> Result<Vec<String>> {
+    let mut files = Vec::new();
+
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+
+        if file_type.is_dir() {
+            if path.file_name().and_then(|n| n.to_str()) == Some(".git") {
+                continue;
+            }
+            files.extend(collect_files(root, &path)?);
+        } else if file_type.is_file() {
+            let relative = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace(std::path::MAIN_SEPARATOR, "/");
+            files.push(relative);
+        }
+    }
+
+    Ok(files)
+}
@@ -3,6 +3,31 @@
 //! This module provides functionality for parsing, resolving, and installing
 //! skill dependencies.
 //!
+//! Version requirements are matched with [`version_matches`], which defers
+//! to the `semver` crate -- the same engine Cargo itself uses -- behind the
+//! crate's `semver` feature (on by default). This gets the full Cargo
+//! requirement grammar for free: `=`, `^`, `~`, `>`, `>=`, `<`, `<=`,
+//! `*`/`x` wildcards, comma-separated `AND` ranges, and the pre-release
+//! matching rules (a pre-release version only satisfies a requirement that
+//! itself names a pre-release on the same `major.minor.patch`). Disabling
+//! the feature falls back to [`version_satisfies_req`], a much narrower
+//! numeric-only comparison kept only so this crate can still build without
+//! the `semver` dependency.
+//!
+//! A resolved graph can be captured as a [`DependencyLock`] via
+//! [`DependencyResolver::lock`], mirroring `Cargo.lock`: every
+//! transitively resolved skill is recorded with its exact version,
+//! source, and a content hash, so a second machine can reproduce the same
+//! install without re-resolving. [`DependencyResolver::verify_lock`]
+//! checks a lock against what's installed to decide whether resolution
+//! can be skipped entirely.
+//!
+//! When two dependencies in the same graph demand incompatible
+//! requirements of the same skill name, [`DependencyResolver::resolve_transitive`]
+//! reports it as [`Error::Resolution`](crate::Error::Resolution), naming
+//! the skill and every requirement that was demanded of it, rather than
+//! silently keeping whichever version was tried first.
+//!
 //! # Example
 //!
 //! ```rust
@@ -14,9 +39,22 @@
 //! # }
 //! ```
 
-use crate::types::{Skill, SkillDependency};
+use crate::types::{DependencyLock, LockedDependency, Skill, SkillDependency, SkillSource};
 use crate::Result;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+/// Every known version of every skill name, as fetched from configured
+/// sources, used as the candidate pool for [`DependencyResolver::resolve_transitive`]
+pub type CandidateVersions = HashMap<String, Vec<Skill>>;
+
+/// Fetches a dependency's own manifest so [`DependencyResolver::resolve_dependencies`]
+/// can descend into its transitive dependencies instead of only ever
+/// listing the root skill's direct ones
+pub trait SkillLoader {
+    /// Loads the manifest for `dep`, e.g. by reading its installed path or
+    /// querying its configured source
+    fn load(&self, dep: &SkillDependency) -> Result<Skill>;
+}
 
 /// Resolves and manages skill dependencies
 pub struct DependencyResolver {
@@ -24,6 +62,10 @@ pub struct DependencyResolver {
     installed: HashMap<String, Skill>,
     /// Skills being resolved (to detect circular dependencies)
     resolving: HashSet<String>,
+    /// Fetches each unresolved dependency's manifest during
+    /// [`resolve_dependencies`]; without one, only the root skill's direct
+    /// dependencies are listed, exactly as before this field existed
+    loader: Option<Box<dyn SkillLoader>>,
 }
 
 impl DependencyResolver {
@@ -32,6 +74,7 @@ impl DependencyResolver {
         Self {
             installed: HashMap::new(),
             resolving: HashSet::new(),
+            loader: None,
         }
     }
 
@@ -44,14 +87,54 @@ impl DependencyResolver {
         resolver
     }
 
+    /// Creates a resolver that uses `loader` to fetch each unresolved
+    /// dependency's own manifest during [`resolve_dependencies`], so the
+    /// returned list is a true topological sort (leaves first) instead of
+    /// only the root skill's direct dependencies
+    pub fn with_loader(loader: impl SkillLoader + 'static) -> Self {
+        let mut resolver = Self::new();
+        resolver.loader = Some(Box::new(loader));
+        resolver
+    }
+
     /// Resolves all dependencies for a skill
     ///
-    /// Returns a list of dependencies that need to be installed,
-    /// in the order they should be installed (dependencies first).
+    /// Returns a list of dependencies that need to be installed, in the
+    /// order they should be installed (dependencies first). When the
+    /// resolver was built with [`with_loader`](Self::with_loader), each
+    /// unresolved dependency's own manifest is loaded and descended into
+    /// before the dependency itself is pushed to the result, so transitive
+    /// dependencies precede the skills that need them; without a loader,
+    /// only the root skill's direct dependencies are listed.
     pub fn resolve_dependencies(&mut self, skill: &Skill) -> Result<Vec<SkillDependency>> {
         self.resolving.clear();
         let mut result = Vec::new();
-        self.resolve_recursive(skill, &mut result)?;
+        let mut path = Vec::new();
+        self.resolve_recursive(skill, &mut result, &mut path, &HashSet::new())?;
+        Ok(result)
+    }
+
+    /// Like [`resolve_dependencies`](Self::resolve_dependencies), but also
+    /// pulls in whichever optional dependencies the named `enabled`
+    /// features activate, per `skill.metadata.features` -- the same way
+    /// Cargo features gate optional dependencies. An activated dependency
+    /// is descended into exactly like a required one (including its own
+    /// transitive dependencies); any optional dependency not named by an
+    /// enabled feature is left out, exactly as without this method.
+    pub fn resolve_with_features(
+        &mut self,
+        skill: &Skill,
+        enabled: &[String],
+    ) -> Result<Vec<SkillDependency>> {
+        self.resolving.clear();
+        let mut result = Vec::new();
+        let mut path = Vec::new();
+        let activated: HashSet<&str> = enabled
+            .iter()
+            .filter_map(|feature| skill.metadata.features.get(feature))
+            .flat_map(|names| names.iter().map(String::as_str))
+            .collect();
+        self.resolve_recursive(skill, &mut result, &mut path, &activated)?;
         Ok(result)
     }
 
@@ -59,11 +142,14 @@ impl DependencyResolver {
         &mut self,
         skill: &Skill,
         result: &mut Vec<SkillDependency>,
+        path: &mut Vec<String>,
+        activated: &HashSet<&str>,
     ) -> Result<()> {
         // Check for circular dependencies
         if self.resolving.contains(&skill.name) {
+            path.push(skill.name.clone());
             return Err(crate::Error::Config {
-                message: format!("Circular dependency detected: {}", skill.name),
+                message: format!("Circular dependency detected: {}", path.join(" -> ")),
             });
         }
 
@@ -72,13 +158,16 @@ impl DependencyResolver {
             return Ok(());
         }
 
-        // Mark as being resolved
+        // Mark as being resolved for the whole descent below this point,
+        // so a cycle anywhere under `skill` is caught, not just at the top
         self.resolving.insert(skill.name.clone());
+        path.push(skill.name.clone());
 
         // Process dependencies
         for dep in &skill.metadata.dependencies {
-            // Skip optional dependencies for now
-            if dep.optional {
+            // Skip optional dependencies, unless an enabled feature names
+            // them as one of the optional deps it activates
+            if dep.optional && !activated.contains(dep.name.as_str()) {
                 continue;
             }
 
@@ -93,24 +182,167 @@ impl DependencyResolver {
                 continue;
             }
 
+            // Descend into the dependency's own manifest before pushing it,
+            // so everything it in turn needs ends up earlier in `result`
+            let dep_skill = match &self.loader {
+                Some(loader) => Some(loader.load(dep)?),
+                None => None,
+            };
+            if let Some(dep_skill) = dep_skill {
+                self.resolve_recursive(&dep_skill, result, path, activated)?;
+            }
+
             result.push(dep.clone());
         }
 
-        // Remove from resolving set
+        // Remove from resolving set now that this branch of the descent is
+        // done, so the same skill can still appear again as a sibling
         self.resolving.remove(&skill.name);
+        path.pop();
 
         Ok(())
     }
 
-    /// Checks if a dependency is satisfied
+    /// Resolves the full transitive dependency tree with backtracking
+    ///
+    /// Given `candidates` -- every known version of every dependency name,
+    /// fetched from configured sources -- runs a depth-first backtracking
+    /// search: for each unresolved dependency, tries candidate versions
+    /// from highest to lowest, picks the first that satisfies the
+    /// dependency's `version_req` and doesn't conflict with a version
+    /// already chosen for the same skill, pushes that candidate's own
+    /// dependencies onto the work queue, and backtracks to the next
+    /// candidate if a later dependency can't be satisfied under the
+    /// current partial assignment.
+    ///
+    /// Returns the chosen version for every transitive dependency, keyed
+    /// by skill name, or an error naming the skill whose requirements
+    /// couldn't be satisfied by any combination of candidates.
+    pub fn resolve_transitive(
+        &self,
+        skill: &Skill,
+        candidates: &CandidateVersions,
+    ) -> Result<HashMap<String, Skill>> {
+        let mut queue: VecDeque<SkillDependency> =
+            skill.metadata.dependencies.iter().cloned().collect();
+        let mut assignment = HashMap::new();
+        let mut seen_requirements: HashMap<String, Vec<String>> = HashMap::new();
+
+        if self.backtrack(&mut queue, &mut assignment, candidates, &mut seen_requirements) {
+            Ok(assignment)
+        } else {
+            // If two or more distinct requirements were demanded of the
+            // same skill name, no candidate pool could ever satisfy both
+            // -- report that conflict by name instead of the generic
+            // "couldn't resolve" message
+            let conflict = seen_requirements.into_iter().find(|(_, reqs)| {
+                reqs.iter().collect::<HashSet<_>>().len() > 1
+            });
+
+            if let Some((name, reqs)) = conflict {
+                let mut requirements: Vec<String> = reqs.into_iter().collect::<HashSet<_>>().into_iter().collect();
+                requirements.sort();
+                return Err(crate::Error::Resolution { name, requirements });
+            }
+
+            Err(crate::Error::Config {
+                message: format!(
+                    "Could not resolve a consistent set of dependency versions for {}",
+                    skill.name
+                ),
+            })
+        }
+    }
+
+    /// Depth-first backtracking step: resolves the front of `queue` against
+    /// `assignment`, recursing on the rest before committing to a choice.
+    /// Records every requirement string seen for each dependency name into
+    /// `seen_requirements`, regardless of how this step resolves, so a
+    /// caller can report conflicting requirements by name on overall
+    /// failure.
+    fn backtrack(
+        &self,
+        queue: &mut VecDeque<SkillDependency>,
+        assignment: &mut HashMap<String, Skill>,
+        candidates: &CandidateVersions,
+        seen_requirements: &mut HashMap<String, Vec<String>>,
+    ) -> bool {
+        let Some(dep) = queue.pop_front() else {
+            return true;
+        };
+
+        if let Some(req) = &dep.version_req {
+            seen_requirements
+                .entry(dep.name.clone())
+                .or_default()
+                .push(req.clone());
+        }
+
+        // Already installed outside this resolution -- must be compatible,
+        // not re-chosen
+        if let Some(installed) = self.installed.get(&dep.name) {
+            if dependency_satisfied_by(&dep, installed) {
+                return self.backtrack(queue, assignment, candidates, seen_requirements);
+            }
+            return dep.optional;
+        }
+
+        // Already chosen earlier in this same resolution
+        if let Some(chosen) = assignment.get(&dep.name) {
+            if dependency_satisfied_by(&dep, chosen) {
+                return self.backtrack(queue, assignment, candidates, seen_requirements);
+            }
+            return dep.optional;
+        }
+
+        let req = dep
+            .version_req
+            .as_deref()
+            .and_then(|r| semver::VersionReq::parse(r).ok());
+
+        let mut options: Vec<&Skill> = candidates
+            .get(&dep.name)
+            .map(|versions| versions.iter().collect())
+            .unwrap_or_default();
+        options.sort_by(|a, b| parsed_version(b).cmp(&parsed_version(a)));
+
+        for candidate in options {
+            if let Some(req) = &req {
+                let Some(version) = parsed_version(candidate) else {
+                    continue;
+                };
+                if !req.matches(&version) {
+                    continue;
+                }
+            }
+
+            assignment.insert(dep.name.clone(), candidate.clone());
+
+            let mut next_queue = queue.clone();
+            for child_dep in &candidate.metadata.dependencies {
+                if !child_dep.optional {
+                    next_queue.push_back(child_dep.clone());
+                }
+            }
+
+            if self.backtrack(&mut next_queue, assignment, candidates, seen_requirements) {
+                return true;
+            }
+
+            assignment.remove(&dep.name);
+        }
+
+        // No candidate satisfied this dependency under the current
+        // assignment; only acceptable if it was optional to begin with
+        dep.optional
+    }
+
+    /// Checks if a dependency is satisfied by the installed version of that
+    /// skill, using full semver matching via [`version_matches`]
     pub fn is_satisfied(&self, dep: &SkillDependency) -> bool {
         if let Some(installed) = self.installed.get(&dep.name) {
-            // Check version requirement if specified
             if let Some(req) = &dep.version_req {
-                if let Some(version) = &installed.version {
-                    // Simple version check (could use semver crate for proper parsing)
-                    return version_satisfies_req(version, req);
-                }
+                return installed.version.as_deref().is_some_and(|v| version_matches(v, req));
             }
             true
         } else {
@@ -118,6 +350,61 @@ impl DependencyResolver {
         }
     }
 
+    /// Builds a reproducible [`DependencyLock`] for `root`'s full
+    /// transitive dependency graph, resolved against `candidates`
+    ///
+    /// Mirrors Cargo's `cargo generate-lockfile`: every transitively
+    /// resolved skill -- including `root` itself -- is recorded with its
+    /// exact chosen version, source, a content hash, and the names of its
+    /// own direct dependencies (the graph's edges). Entries are keyed by
+    /// name in a [`BTreeMap`], so the serialized file is order-stable and
+    /// produces minimal diffs between resolutions.
+    pub fn lock(&self, root: &Skill, candidates: &CandidateVersions) -> Result<DependencyLock> {
+        let resolved = self.resolve_transitive(root, candidates)?;
+
+        let mut skills = BTreeMap::new();
+        skills.insert(root.name.clone(), locked_entry(root));
+        for skill in resolved.values() {
+            skills.insert(skill.name.clone(), locked_entry(skill));
+        }
+
+        Ok(DependencyLock { version: 1, skills })
+    }
+
+    /// Checks `lock` against what's currently installed, returning the
+    /// names of any entries that are missing or whose content hash has
+    /// drifted from the installed skill
+    ///
+    /// An empty result means the installed tree still matches `lock`
+    /// exactly, so [`resolve_transitive`](Self::resolve_transitive) can be
+    /// skipped entirely -- the same short-circuit an unchanged
+    /// `Cargo.lock` gives `cargo build`.
+    pub fn verify_lock(&self, lock: &DependencyLock) -> Vec<String> {
+        let mut drifted = Vec::new();
+        for (name, locked) in &lock.skills {
+            match self.installed.get(name) {
+                Some(skill) if locked_entry(skill) == *locked => {}
+                _ => drifted.push(name.clone()),
+            }
+        }
+        drifted
+    }
+
+    /// Like [`is_satisfied`](Self::is_satisfied), but first consults
+    /// `lock` -- if it already records a version for `dep.name` that
+    /// satisfies the requirement, that recorded resolution can be trusted
+    /// and the installed-skill check is skipped entirely
+    pub fn is_satisfied_via_lock(&self, dep: &SkillDependency, lock: &DependencyLock) -> bool {
+        if let Some(locked) = lock.skills.get(&dep.name) {
+            match &dep.version_req {
+                Some(req) if version_matches(&locked.version, req) => return true,
+                None => return true,
+                _ => {}
+            }
+        }
+        self.is_satisfied(dep)
+    }
+
     /// Adds an installed skill to the resolver
     pub fn add_installed(&mut self, skill: Skill) {
         self.installed.insert(skill.name.clone(), skill);
@@ -135,10 +422,93 @@ impl Default for DependencyResolver {
     }
 }
 
+/// Checks whether `skill` satisfies `dep`'s version requirement via
+/// [`version_matches`]
+fn dependency_satisfied_by(dep: &SkillDependency, skill: &Skill) -> bool {
+    let Some(req_str) = &dep.version_req else {
+        return true;
+    };
+    let Some(version) = &skill.version else {
+        return false;
+    };
+
+    version_matches(version, req_str)
+}
+
+/// Parses a skill's `version` field as semver, if present and well-formed
+fn parsed_version(skill: &Skill) -> Option<semver::Version> {
+    skill.version.as_deref().and_then(|v| semver::Version::parse(v).ok())
+}
+
+/// Builds the [`DependencyLock`] entry for one resolved skill
+fn locked_entry(skill: &Skill) -> LockedDependency {
+    LockedDependency {
+        version: skill.version.clone().unwrap_or_default(),
+        source: source_descriptor(&skill.source),
+        content_hash: content_hash(skill),
+        dependencies: skill
+            .metadata
+            .dependencies
+            .iter()
+            .filter(|dep| !dep.optional)
+            .map(|dep| dep.name.clone())
+            .collect(),
+    }
+}
+
+/// Human-readable description of where a skill's content came from, used
+/// as the `source` field of a [`LockedDependency`]
+fn source_descriptor(source: &SkillSource) -> String {
+    match source {
+        SkillSource::Local { path } => path.display().to_string(),
+        SkillSource::GitHub { owner, repo, .. } => format!("github:{owner}/{repo}"),
+        SkillSource::Remote { url, .. } => url.clone(),
+        SkillSource::Oci { reference, .. } => format!("oci:{reference}"),
+    }
+}
+
+/// SHA-256 digest of `skill`'s installed tree (see [`crate::core::hash_tree`]),
+/// used to detect drift between a [`DependencyLock`] entry and what's
+/// installed -- unlike a hash over `skill`'s name/version/source alone,
+/// this catches a file hand-edited (or tampered with) on disk even when
+/// none of that metadata changed. An unreadable tree hashes to the empty
+/// string, which can never match a real lock entry, so it's reported as
+/// drifted rather than panicking [`DependencyResolver::verify_lock`].
+fn content_hash(skill: &Skill) -> String {
+    crate::core::hash_tree(&skill.path).unwrap_or_default()
+}
+
+/// Whether `version` satisfies requirement string `req`
+///
+/// With the crate's `semver` feature enabled (the default), parses both
+/// through the `semver` crate, so `req` can use the full Cargo-style
+/// grammar (`^`, `~`, comparator ranges, wildcards, comma-separated `AND`
+/// ranges, pre-release rules); falls back to [`version_satisfies_req`]
+/// when either side fails to parse as strict semver, e.g. a two-component
+/// version like `"1.0"`.
+#[cfg(feature = "semver")]
+pub fn version_matches(version: &str, req: &str) -> bool {
+    match (semver::Version::parse(version), semver::VersionReq::parse(req)) {
+        (Ok(v), Ok(r)) => r.matches(&v),
+        _ => version_satisfies_req(version, req),
+    }
+}
+
+/// Whether `version` satisfies requirement string `req`, using only the
+/// simplified numeric comparison in [`version_satisfies_req`] -- the
+/// crate's `semver` feature is disabled, so the real `semver` crate isn't
+/// available as a dependency here
+#[cfg(not(feature = "semver"))]
+pub fn version_matches(version: &str, req: &str) -> bool {
+    version_satisfies_req(version, req)
+}
+
 /// Checks if a version satisfies a version requirement
 ///
-/// This is a simplified implementation. For production use,
-/// consider using the `semver` crate.
+/// This is a simplified implementation, understanding only exact matches
+/// and `^`/`>=`/`>` with numeric dotted components. Used as the
+/// `semver`-feature-disabled fallback for [`version_matches`]; prefer
+/// that function over calling this one directly.
 fn version_satisfies_req(version: &str, req: &str) -> bool {
     // Simple exact match
     if version == req {
@@ -208,10 +578,22 @@ fn compare_versions(v1: &str, v2: &str) -> i32 {
     0
 }
 
-/// Parses dependencies from a skill configuration file
+/// Dependencies and named feature sets parsed from a skill manifest, as
+/// returned by [`parse_dependencies`]
+#[derive(Debug, Clone, Default)]
+pub struct ParsedDependencies {
+    /// Every dependency declared in the manifest, including optional ones
+    pub dependencies: Vec<SkillDependency>,
+    /// Feature name -> names of the optional dependencies it activates,
+    /// read from a `"features"` object alongside `"dependencies"`
+    pub features: HashMap<String, Vec<String>>,
+}
+
+/// Parses dependencies and named feature sets from a skill configuration
+/// file
 ///
 /// This function can parse dependencies from various skill formats
-pub fn parse_dependencies(value: &serde_json::Value) -> Vec<SkillDependency> {
+pub fn parse_dependencies(value: &serde_json::Value) -> ParsedDependencies {
     let mut deps = Vec::new();
 
     if let Some(deps_array) = value.get("dependencies").and_then(|d| d.as_array()) {
@@ -263,12 +645,194 @@ pub fn parse_dependencies(value: &serde_json::Value) -> Vec<SkillDependency> {
         }
     }
 
-    deps
+    let mut features = HashMap::new();
+    if let Some(features_obj) = value.get("features").and_then(|f| f.as_object()) {
+        for (name, activates) in features_obj {
+            let activated_deps = activates
+                .as_array()
+                .map(|arr| {
+                    arr.iter()
+                        .filter_map(|v| v.as_str().map(str::to_string))
+                        .collect()
+                })
+                .unwrap_or_default();
+            features.insert(name.clone(), activated_deps);
+        }
+    }
+
+    ParsedDependencies {
+        dependencies: deps,
+        features,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::{SkillFormat, SkillMetadata, SkillSource, SourceType};
+    use std::path::PathBuf;
+
+    fn test_skill(name: &str, version: &str, deps: Vec<SkillDependency>) -> Skill {
+        Skill {
+            id: name.to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            version: Some(version.to_string()),
+            source: SkillSource::Local {
+                path: PathBuf::from("/test").into(),
+            },
+            source_type: SourceType::Local,
+            path: PathBuf::from("/test").into(),
+            installed_at: chrono::Utc::now(),
+            metadata: SkillMetadata {
+                dependencies: deps,
+                ..Default::default()
+            },
+            format: SkillFormat::GenericMarkdown,
+        }
+    }
+
+    fn dep(name: &str, version_req: Option<&str>) -> SkillDependency {
+        SkillDependency {
+            name: name.to_string(),
+            version_req: version_req.map(|s| s.to_string()),
+            source: None,
+            optional: false,
+        }
+    }
+
+    /// Loads manifests from an in-memory map, keyed by skill name, for
+    /// exercising [`DependencyResolver::with_loader`] without a real source
+    struct InMemoryLoader {
+        skills: HashMap<String, Skill>,
+    }
+
+    impl SkillLoader for InMemoryLoader {
+        fn load(&self, dep: &SkillDependency) -> Result<Skill> {
+            self.skills.get(&dep.name).cloned().ok_or_else(|| crate::Error::Config {
+                message: format!("no manifest available for dependency '{}'", dep.name),
+            })
+        }
+    }
+
+    #[test]
+    fn test_resolve_transitive_picks_highest_satisfying_version() {
+        let root = test_skill("root", "1.0.0", vec![dep("a", Some("^1.0.0"))]);
+        let candidates: CandidateVersions = HashMap::from([(
+            "a".to_string(),
+            vec![
+                test_skill("a", "1.0.0", vec![]),
+                test_skill("a", "1.2.0", vec![]),
+                test_skill("a", "2.0.0", vec![]),
+            ],
+        )]);
+
+        let resolver = DependencyResolver::new();
+        let resolved = resolver.resolve_transitive(&root, &candidates).unwrap();
+
+        assert_eq!(resolved["a"].version.as_deref(), Some("1.2.0"));
+    }
+
+    #[test]
+    fn test_resolve_transitive_pulls_in_transitive_deps() {
+        let root = test_skill("root", "1.0.0", vec![dep("a", None)]);
+        let candidates: CandidateVersions = HashMap::from([
+            (
+                "a".to_string(),
+                vec![test_skill("a", "1.0.0", vec![dep("b", Some(">=1.0.0"))])],
+            ),
+            (
+                "b".to_string(),
+                vec![test_skill("b", "1.0.0", vec![])],
+            ),
+        ]);
+
+        let resolver = DependencyResolver::new();
+        let resolved = resolver.resolve_transitive(&root, &candidates).unwrap();
+
+        assert!(resolved.contains_key("a"));
+        assert!(resolved.contains_key("b"));
+    }
+
+    #[test]
+    fn test_resolve_transitive_backtracks_on_conflict() {
+        // `a` accepts any version of `shared`, so the search tries the
+        // highest candidate first; `b` pins an older version, forcing the
+        // search to backtrack and re-pick `shared` to satisfy both.
+        let root = test_skill("root", "1.0.0", vec![dep("a", None), dep("b", None)]);
+        let candidates: CandidateVersions = HashMap::from([
+            (
+                "a".to_string(),
+                vec![test_skill("a", "1.0.0", vec![dep("shared", None)])],
+            ),
+            (
+                "b".to_string(),
+                vec![test_skill("b", "1.0.0", vec![dep("shared", Some("=1.0.0"))])],
+            ),
+            (
+                "shared".to_string(),
+                vec![
+                    test_skill("shared", "1.0.0", vec![]),
+                    test_skill("shared", "2.0.0", vec![]),
+                ],
+            ),
+        ]);
+
+        let resolver = DependencyResolver::new();
+        let resolved = resolver.resolve_transitive(&root, &candidates).unwrap();
+
+        assert_eq!(resolved["shared"].version.as_deref(), Some("1.0.0"));
+    }
+
+    #[test]
+    fn test_resolve_transitive_reports_conflicting_requirements_by_name() {
+        // `a` requires `shared` ^1.0.0, `b` requires `shared` ^2.0.0 -- no
+        // single version can satisfy both, so resolution must fail by
+        // naming `shared` and both requirements, not a generic message
+        let root = test_skill("root", "1.0.0", vec![dep("a", None), dep("b", None)]);
+        let candidates: CandidateVersions = HashMap::from([
+            (
+                "a".to_string(),
+                vec![test_skill("a", "1.0.0", vec![dep("shared", Some("^1.0.0"))])],
+            ),
+            (
+                "b".to_string(),
+                vec![test_skill("b", "1.0.0", vec![dep("shared", Some("^2.0.0"))])],
+            ),
+            (
+                "shared".to_string(),
+                vec![
+                    test_skill("shared", "1.0.0", vec![]),
+                    test_skill("shared", "2.0.0", vec![]),
+                ],
+            ),
+        ]);
+
+        let resolver = DependencyResolver::new();
+        let err = resolver.resolve_transitive(&root, &candidates).unwrap_err();
+
+        match err {
+            crate::Error::Resolution { name, requirements } => {
+                assert_eq!(name, "shared");
+                let mut reqs = requirements;
+                reqs.sort();
+                assert_eq!(reqs, vec!["^1.0.0".to_string(), "^2.0.0".to_string()]);
+            }
+            other => panic!("expected Error::Resolution, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_resolve_transitive_fails_with_no_satisfying_candidate() {
+        let root = test_skill("root", "1.0.0", vec![dep("a", Some("^2.0.0"))]);
+        let candidates: CandidateVersions = HashMap::from([(
+            "a".to_string(),
+            vec![test_skill("a", "1.0.0", vec![])],
+        )]);
+
+        let resolver = DependencyResolver::new();
+        assert!(resolver.resolve_transitive(&root, &candidates).is_err());
+    }
 
     #[test]
     fn test_compare_versions() {
@@ -288,6 +852,141 @@ mod tests {
         assert!(!version_satisfies_req("0.9.0", ">=1.0.0"));
     }
 
+    #[test]
+    fn test_version_matches_full_grammar() {
+        // Caret widens below 1.0 down to the last nonzero component
+        assert!(version_matches("0.2.5", "^0.2.3"));
+        assert!(!version_matches("0.3.0", "^0.2.3"));
+        assert!(version_matches("0.0.3", "^0.0.3"));
+        assert!(!version_matches("0.0.4", "^0.0.3"));
+
+        // Tilde pins the most-specific supplied component
+        assert!(version_matches("1.2.5", "~1.2.3"));
+        assert!(!version_matches("1.3.0", "~1.2.3"));
+
+        // Wildcards
+        assert!(version_matches("1.5.0", "1.*"));
+        assert!(!version_matches("2.0.0", "1.*"));
+
+        // Comma-separated AND ranges
+        assert!(version_matches("1.5.0", ">=1.2.0, <2.0.0"));
+        assert!(!version_matches("2.0.0", ">=1.2.0, <2.0.0"));
+
+        // A pre-release version only matches a requirement naming a
+        // pre-release on the same major.minor.patch
+        assert!(!version_matches("1.0.0-alpha.1", ">=1.0.0"));
+        assert!(version_matches("1.0.0-alpha.1", ">=1.0.0-alpha"));
+    }
+
+    #[test]
+    fn test_resolve_dependencies_descends_transitively() {
+        // root -> a -> b, with no loader configured the old behavior
+        // (direct dependencies only) is preserved
+        let b = test_skill("b", "1.0.0", vec![]);
+        let a = test_skill("a", "1.0.0", vec![dep("b", None)]);
+        let root = test_skill("root", "1.0.0", vec![dep("a", None)]);
+
+        let mut resolver = DependencyResolver::new();
+        let result = resolver.resolve_dependencies(&root).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "a");
+
+        // With a loader, the resolver descends into `a`'s own manifest and
+        // `b` -- the leaf -- precedes `a` in the install order
+        let loader = InMemoryLoader {
+            skills: HashMap::from([("a".to_string(), a), ("b".to_string(), b)]),
+        };
+        let mut resolver = DependencyResolver::with_loader(loader);
+        let result = resolver.resolve_dependencies(&root).unwrap();
+
+        let names: Vec<&str> = result.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["b", "a"]);
+    }
+
+    #[test]
+    fn test_resolve_dependencies_detects_circular_chain_with_full_path() {
+        // a -> b -> a
+        let a = test_skill("a", "1.0.0", vec![dep("b", None)]);
+        let b = test_skill("b", "1.0.0", vec![dep("a", None)]);
+
+        let loader = InMemoryLoader {
+            skills: HashMap::from([("a".to_string(), a.clone()), ("b".to_string(), b)]),
+        };
+        let mut resolver = DependencyResolver::with_loader(loader);
+
+        let err = resolver.resolve_dependencies(&a).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("a -> b -> a"), "unexpected message: {message}");
+    }
+
+    #[test]
+    fn test_lock_produces_order_stable_entries_with_edges() {
+        let root = test_skill("root", "1.0.0", vec![dep("b", None), dep("a", Some("^1.0.0"))]);
+        let candidates: CandidateVersions = HashMap::from([
+            ("a".to_string(), vec![test_skill("a", "1.2.0", vec![])]),
+            (
+                "b".to_string(),
+                vec![test_skill("b", "1.0.0", vec![dep("a", None)])],
+            ),
+        ]);
+
+        let resolver = DependencyResolver::new();
+        let lock = resolver.lock(&root, &candidates).unwrap();
+
+        let names: Vec<&String> = lock.skills.keys().collect();
+        assert_eq!(names, vec!["a", "b", "root"]);
+        assert_eq!(lock.skills["a"].version, "1.2.0");
+        assert_eq!(
+            lock.skills["root"].dependencies,
+            vec!["b".to_string(), "a".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_verify_lock_detects_drift() {
+        let a_v1 = test_skill("a", "1.0.0", vec![]);
+        let root = test_skill("root", "1.0.0", vec![dep("a", None)]);
+        let candidates: CandidateVersions = HashMap::from([("a".to_string(), vec![a_v1.clone()])]);
+
+        let resolver = DependencyResolver::new();
+        let lock = resolver.lock(&root, &candidates).unwrap();
+
+        let matching = DependencyResolver::with_installed(vec![root.clone(), a_v1]);
+        assert!(matching.verify_lock(&lock).is_empty());
+
+        let a_v2 = test_skill("a", "2.0.0", vec![]);
+        let drifted = DependencyResolver::with_installed(vec![root, a_v2]);
+        assert_eq!(drifted.verify_lock(&lock), vec!["a".to_string()]);
+    }
+
+    /// Same name, version, and source before and after -- only the bytes
+    /// on disk change -- so this only drifts if `content_hash` actually
+    /// hashes the installed tree ([`crate::core::hash_tree`]) instead of
+    /// just `skill`'s metadata, which would report no drift at all here.
+    #[test]
+    fn test_verify_lock_detects_drift_from_file_edit() {
+        let dir = std::env::temp_dir().join(format!("alltheskills-test-drift-{}-{}", std::process::id(), line!()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("SKILL.md"), "original content").unwrap();
+
+        let mut a = test_skill("a", "1.0.0", vec![]);
+        a.path = dir.clone().into();
+        let root = test_skill("root", "1.0.0", vec![dep("a", None)]);
+        let candidates: CandidateVersions = HashMap::from([("a".to_string(), vec![a.clone()])]);
+
+        let resolver = DependencyResolver::new();
+        let lock = resolver.lock(&root, &candidates).unwrap();
+
+        let matching = DependencyResolver::with_installed(vec![root.clone(), a.clone()]);
+        assert!(matching.verify_lock(&lock).is_empty());
+
+        std::fs::write(dir.join("SKILL.md"), "tampered content").unwrap();
+        let edited = DependencyResolver::with_installed(vec![root, a]);
+        assert_eq!(edited.verify_lock(&lock), vec!["a".to_string()]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn test_parse_dependencies() {
         let json = serde_json::json!({
@@ -305,7 +1004,8 @@ mod tests {
             ]
         });
 
-        let deps = parse_dependencies(&json);
+        let parsed = parse_dependencies(&json);
+        let deps = parsed.dependencies;
         assert_eq!(deps.len(), 3);
 
         assert_eq!(deps[0].name, "skill-a");
@@ -317,5 +1017,52 @@ mod tests {
 
         assert_eq!(deps[2].name, "skill-c");
         assert!(deps[2].optional);
+
+        assert!(parsed.features.is_empty());
+    }
+
+    #[test]
+    fn test_parse_dependencies_reads_features_object() {
+        let json = serde_json::json!({
+            "dependencies": [
+                {"name": "pdf-tools", "optional": true},
+                {"name": "ocr-tools", "optional": true}
+            ],
+            "features": {
+                "pdf": ["pdf-tools"]
+            }
+        });
+
+        let parsed = parse_dependencies(&json);
+        assert_eq!(parsed.dependencies.len(), 2);
+        assert_eq!(
+            parsed.features.get("pdf"),
+            Some(&vec!["pdf-tools".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_resolve_with_features_activates_only_named_feature() {
+        let mut pdf_dep = dep("pdf-tools", None);
+        pdf_dep.optional = true;
+        let mut ocr_dep = dep("ocr-tools", None);
+        ocr_dep.optional = true;
+
+        let mut root = test_skill("root", "1.0.0", vec![pdf_dep, ocr_dep]);
+        root.metadata.features =
+            HashMap::from([("pdf".to_string(), vec!["pdf-tools".to_string()])]);
+
+        // Without any enabled features both optionals stay skipped,
+        // exactly like the pre-existing resolve_dependencies behavior
+        let mut resolver = DependencyResolver::new();
+        assert!(resolver.resolve_dependencies(&root).unwrap().is_empty());
+
+        // Enabling "pdf" pulls in pdf-tools but leaves ocr-tools out
+        let mut resolver = DependencyResolver::new();
+        let result = resolver
+            .resolve_with_features(&root, &["pdf".to_string()])
+            .unwrap();
+        let names: Vec<&str> = result.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["pdf-tools"]);
     }
 }
@@ -32,6 +32,17 @@ pub enum Error {
     #[error("Installation failed: {reason}")]
     Install { reason: String },
 
+    /// An external `PluginProvider` process misbehaved or exited unexpectedly
+    #[error("Plugin `{plugin}` error: {message} (exit status: {exit_status:?})")]
+    Plugin {
+        /// Name or path of the plugin executable
+        plugin: String,
+        /// Description of what went wrong
+        message: String,
+        /// The child process's exit status, if it had already exited
+        exit_status: Option<i32>,
+    },
+
     /// Git operation failed
     #[error("Git error: {source}")]
     Git {
@@ -45,6 +56,36 @@ pub enum Error {
         #[source]
         source: serde_json::Error,
     },
+
+    /// An HTTP API call returned a non-success status
+    #[error("HTTP error ({}): {message}", status.map(|s| s.to_string()).unwrap_or_else(|| "unknown".to_string()))]
+    Http {
+        /// Status code, if the request reached the server at all
+        status: Option<u16>,
+        /// Description of what was being requested
+        message: String,
+    },
+
+    /// One or more providers failed while listing skills, bundled together
+    /// instead of being dropped so a caller can inspect which sources
+    /// failed and why
+    #[error("{} provider(s) failed: {}", failures.len(), failures.iter().map(|(name, e)| format!("{name}: {e}")).collect::<Vec<_>>().join("; "))]
+    Aggregate {
+        /// Provider name paired with the error it returned
+        failures: Vec<(String, Error)>,
+    },
+
+    /// Two or more dependencies in the same resolution graph demanded
+    /// incompatible version requirements of the same skill, so no single
+    /// version could satisfy all of them
+    #[error("No version of `{name}` satisfies every requirement demanded of it: {requirements:?}")]
+    Resolution {
+        /// Name of the skill with conflicting requirements
+        name: String,
+        /// Every distinct version requirement demanded of `name` across
+        /// the graph
+        requirements: Vec<String>,
+    },
 }
 
 impl From<std::io::Error> for Error {
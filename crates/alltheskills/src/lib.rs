@@ -48,12 +48,22 @@
 //! | Local | Custom paths | Any format |
 
 use futures::stream::{self, StreamExt};
+use std::sync::Mutex;
 
+pub mod cache;
+pub mod converter;
 pub mod core;
+pub mod dependencies;
 pub mod error;
+pub mod manifest;
 pub mod providers;
+pub mod schema;
 pub mod types;
+pub mod utils;
+pub mod workspace;
 
+pub use cache::SkillCache;
+pub use converter::{convert_skill, ConvertTarget};
 pub use error::Error;
 pub use providers::{KnownSources, SkillProvider};
 pub use types::{AllSkillsConfig, Skill, SkillScope, SourceType};
@@ -87,53 +97,257 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// ```
 pub struct SkillReader {
     _config: AllSkillsConfig,
-    providers: Vec<Box<dyn crate::providers::SkillProvider>>,
+    /// Each provider paired with the name of the [`crate::types::SourceConfig`]
+    /// it was built for, when known -- see
+    /// [`add_provider_for_source`](Self::add_provider_for_source). `None`
+    /// for providers added via the plain [`add_provider`](Self::add_provider)/
+    /// [`add_boxed_provider`](Self::add_boxed_provider), which fall back to
+    /// a synthetic default config keyed by the provider's own display name.
+    providers: Vec<(Option<String>, Box<dyn crate::providers::SkillProvider>)>,
+    cache: Mutex<Option<SkillCache>>,
+}
+
+/// Result of [`SkillReader::list_all_skills_detailed`]: every skill that
+/// was discovered, plus the name and error of every provider that failed
+/// along the way
+///
+/// `errors` being non-empty doesn't mean `skills` is wrong -- it means
+/// some sources couldn't be reached or parsed, so the result is a partial
+/// view rather than a complete one.
+#[derive(Debug, Default)]
+pub struct ListOutcome {
+    /// Skills successfully discovered from every provider that succeeded
+    pub skills: Vec<Skill>,
+    /// Provider name paired with the error it returned
+    pub errors: Vec<(String, Error)>,
+}
+
+impl ListOutcome {
+    /// Whether every provider succeeded
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Bundles `errors` into a single [`Error::Aggregate`], if there are
+    /// any
+    pub fn into_result(self) -> Result<Vec<Skill>> {
+        if self.errors.is_empty() {
+            Ok(self.skills)
+        } else {
+            Err(Error::Aggregate { failures: self.errors })
+        }
+    }
 }
 
 impl SkillReader {
     /// Creates a new `SkillReader` with the given configuration
+    ///
+    /// Opens a [`SkillCache`] under `config.cache_dir`; if that fails (the
+    /// directory isn't writable, say), the reader falls back to rescanning
+    /// every provider on every call instead of failing outright.
     pub fn new(config: AllSkillsConfig) -> Self {
+        let cache = SkillCache::open(&config.cache_dir).ok();
         Self {
             _config: config,
             providers: Vec::new(),
+            cache: Mutex::new(cache),
         }
     }
 
     /// Adds a provider to the skill reader
     ///
-    /// Providers are queried in parallel when listing skills.
+    /// Providers are queried in parallel when listing skills. Not
+    /// associated with any particular [`crate::types::SourceConfig`]; use
+    /// [`add_provider_for_source`](Self::add_provider_for_source) when one
+    /// built via [`providers::ProviderRegistry::build_from_config`] needs
+    /// its originating config (e.g. two same-`SourceType` sources) tracked
+    /// and cached independently.
     pub fn add_provider<P: crate::providers::SkillProvider + 'static>(&mut self, provider: P) {
-        self.providers.push(Box::new(provider));
+        self.providers.push((None, Box::new(provider)));
+    }
+
+    /// Adds an already-boxed provider, e.g. one built by
+    /// [`providers::ProviderRegistry::build_from_config`]
+    pub fn add_boxed_provider(&mut self, provider: Box<dyn crate::providers::SkillProvider>) {
+        self.providers.push((None, provider));
+    }
+
+    /// Adds an already-boxed provider built for `source`, tagging it with
+    /// `source.name` so [`list_all_skills`](Self::list_all_skills) resolves
+    /// it back to exactly this [`crate::types::SourceConfig`] -- not just
+    /// any config sharing its `source_type` -- when looking up its scan
+    /// config and cache row
+    pub fn add_provider_for_source(&mut self, source: &crate::types::SourceConfig, provider: Box<dyn crate::providers::SkillProvider>) {
+        self.providers.push((Some(source.name.clone()), provider));
+    }
+
+    /// Discovers external plugin executables in `dir` and registers each as
+    /// a provider, so they're queried by [`list_all_skills`](Self::list_all_skills)
+    /// alongside the built-in providers.
+    pub async fn add_plugins_from_dir(&mut self, dir: &std::path::Path) {
+        for plugin in crate::providers::PluginProvider::discover(dir).await {
+            self.providers.push((None, Box::new(plugin)));
+        }
     }
 
     /// Lists all skills from all configured providers
     ///
     /// This method queries all registered providers concurrently and
-    /// returns a combined list of all discovered skills.
+    /// returns a combined list of all discovered skills. A provider is
+    /// only actually rescanned when its
+    /// [`scan_key`](crate::providers::SkillProvider::scan_key) has
+    /// changed since the last call -- otherwise the cached result from
+    /// [`AllSkillsConfig::cache_dir`] is returned directly.
+    ///
+    /// A provider that fails is silently excluded from the result; use
+    /// [`list_all_skills_detailed`](Self::list_all_skills_detailed) if you
+    /// need to know whether that happened.
     pub async fn list_all_skills(&self) -> Result<Vec<Skill>> {
-        let futures = self.providers.iter().map(|p| async {
-            let config = crate::types::SourceConfig {
-                name: p.name().to_string(),
-                source_type: SourceType::Local,
-                enabled: true,
-                scope: crate::types::SkillScope::User,
-                priority: 0,
-            };
-            p.list_skills(&config).await
+        Ok(self.list_all_skills_inner(false).await?.skills)
+    }
+
+    /// Lists all skills from all configured providers, reporting which
+    /// providers (if any) failed alongside the skills successfully
+    /// discovered from the rest
+    ///
+    /// Unlike [`list_all_skills`](Self::list_all_skills), a per-provider
+    /// failure never causes the whole call to fail or silently drops the
+    /// error to stderr -- it's returned in
+    /// [`ListOutcome::errors`] so a caller can decide whether a partial
+    /// result is acceptable.
+    pub async fn list_all_skills_detailed(&self) -> Result<ListOutcome> {
+        self.list_all_skills_inner(false).await
+    }
+
+    /// Forces a full rescan of every provider, ignoring any cached scan
+    /// key, and repopulates the cache with the fresh results
+    pub async fn refresh(&self) -> Result<Vec<Skill>> {
+        Ok(self.list_all_skills_inner(true).await?.skills)
+    }
+
+    /// Drops every cached row for `source_name`, so the next
+    /// [`list_all_skills`](Self::list_all_skills) call rescans that
+    /// source from scratch
+    pub fn invalidate(&self, source_name: &str) {
+        if let Ok(mut guard) = self.cache.lock() {
+            if let Some(cache) = guard.as_mut() {
+                let _ = cache.invalidate(source_name);
+            }
+        }
+    }
+
+    async fn list_all_skills_inner(&self, force: bool) -> Result<ListOutcome> {
+        let futures = self.providers.iter().map(|(config_name, p)| async {
+            // Matched by name, not bare `source_type()`: a `SourceType`
+            // variant like `SourceType::GitHub` is shared by every
+            // `SourceConfig` of that type, so matching on type alone would
+            // collapse two same-type sources (e.g. two GitHub orgs) onto
+            // whichever one happens to sort first.
+            let config = config_name
+                .as_deref()
+                .and_then(|name| self._config.sources.iter().find(|s| s.name == name))
+                .cloned()
+                .unwrap_or_else(|| crate::types::SourceConfig {
+                    name: p.name().to_string(),
+                    source_type: p.source_type(),
+                    enabled: true,
+                    scope: crate::types::SkillScope::User,
+                    priority: 0,
+                    github: None,
+                    path: None,
+                    pattern: None,
+                    registry: None,
+                    custom: None,
+                    oci: None,
+                });
+
+            let source_name = config.name.clone();
+            let current_key = p.scan_key(&config);
+            if !force {
+                if let Some(cached) = self.cached_skills_if_fresh(&source_name, current_key.as_deref()) {
+                    return (p.name(), Ok(cached));
+                }
+            }
+
+            let retry_config = crate::core::RetryConfig::from_config(&self._config);
+            let result = crate::core::with_retry(retry_config, || p.list_skills(&config)).await;
+            if let Ok(skills) = &result {
+                self.store_in_cache(&source_name, current_key.as_deref(), skills);
+            }
+            (p.name(), result)
         });
 
-        let results: Vec<Result<Vec<Skill>>> =
+        let results: Vec<(&str, Result<Vec<Skill>>)> =
             stream::iter(futures).buffer_unordered(10).collect().await;
 
-        let mut all_skills = Vec::new();
-        for result in results {
+        let mut skills = Vec::new();
+        let mut errors = Vec::new();
+        for (provider_name, result) in results {
             match result {
-                Ok(skills) => all_skills.extend(skills),
-                Err(e) => eprintln!("Failed to list skills: {}", e),
+                Ok(provider_skills) => skills.extend(provider_skills),
+                Err(e) => errors.push((provider_name.to_string(), e)),
             }
         }
 
-        Ok(all_skills)
+        Ok(ListOutcome { skills, errors })
+    }
+
+    /// Returns the cached skills for `source_name` if a scan key was
+    /// recorded for it and it matches `current_key`
+    fn cached_skills_if_fresh(&self, source_name: &str, current_key: Option<&str>) -> Option<Vec<Skill>> {
+        let current_key = current_key?;
+        let guard = self.cache.lock().ok()?;
+        let cache = guard.as_ref()?;
+        let cached_key = cache.scan_key(source_name).ok()??;
+        if cached_key != current_key {
+            return None;
+        }
+        cache.skills_for_source(source_name).ok()
+    }
+
+    /// Upserts `skills` into the cache under `source_name`, recording
+    /// `scan_key` as the condition under which they stay fresh
+    fn store_in_cache(&self, source_name: &str, scan_key: Option<&str>, skills: &[Skill]) {
+        if let Ok(mut guard) = self.cache.lock() {
+            if let Some(cache) = guard.as_mut() {
+                let _ = cache.upsert_source(source_name, scan_key, skills);
+            }
+        }
+    }
+
+    /// Checks out every git-backed skill currently known to this reader at
+    /// the commit recorded for it in `lock`, skipping skills the lock
+    /// doesn't mention or that aren't git-backed
+    ///
+    /// Unlike the CLI's `update --locked`, this never fetches -- it only
+    /// resets an already-cloned working tree to a commit it's expected to
+    /// already have, matching what [`GitHubProvider::install`](crate::providers::GitHubProvider)
+    /// just cloned (or a previous run left behind) against a team's
+    /// checked-in `alltheskills.lock`.
+    pub async fn sync_locked(&self, lock: &crate::types::Lockfile) -> Result<Vec<Skill>> {
+        let skills = self.list_all_skills().await?;
+        let mut synced = Vec::with_capacity(skills.len());
+
+        for skill in skills {
+            let Some(locked) = lock.skills.get(&skill.id) else {
+                synced.push(skill);
+                continue;
+            };
+            if !matches!(skill.source, crate::types::SkillSource::GitHub { .. }) {
+                synced.push(skill);
+                continue;
+            }
+
+            let path = skill.path.clone();
+            let commit = locked.commit.clone();
+            tokio::task::spawn_blocking(move || crate::core::checkout_commit(&path, &commit))
+                .await
+                .map_err(|e| Error::Config { message: format!("sync_locked task panicked: {e}") })??;
+
+            synced.push(skill);
+        }
+
+        Ok(synced)
     }
 
     /// Searches for skills matching the given predicate
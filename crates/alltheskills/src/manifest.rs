@@ -0,0 +1,77 @@
+//! Shared, typed skill manifest for providers that don't need their own
+//! dedicated [`crate::schema::ManifestFormat`] entry
+//!
+//! [`KiloProvider`](crate::providers::kilo::KiloProvider) and
+//! [`VercelProvider`](crate::providers::vercel::VercelProvider) used to
+//! parse their manifest by indexing into a raw
+//! `serde_yaml::Value`/`serde_json::Value` (`config["name"].as_str()...`),
+//! which silently turns a field of the wrong type into an empty string
+//! or `None` instead of a descriptive error, and duplicated the same
+//! extraction logic in both files. [`SkillManifest`] is one tolerant
+//! `#[derive(Deserialize)]` struct either provider can deserialize into --
+//! JSON and YAML both land on the same `serde::Deserialize` trait, so one
+//! type covers both -- while every field stays `#[serde(default)]` (no
+//! `deny_unknown_fields`), so a manifest missing a field, or carrying
+//! extra ones neither provider reads, still parses; only a field present
+//! with the *wrong type* (e.g. `name: 123`) now surfaces as a real
+//! [`Error::Parse`], the way a typed `cargo_toml::Manifest` would.
+//!
+//! Dependency lists aren't included here: [`crate::dependencies::parse_dependencies`]
+//! already accepts richer shapes (bare strings, `{name, version, optional}`
+//! objects, a `features` map) than a plain `Vec<SkillDependency>` field
+//! could deserialize directly, so providers that need dependencies keep
+//! going through that function against the raw value.
+
+use serde::Deserialize;
+
+use crate::workspace::Inheritable;
+use crate::{Error, Result};
+
+/// Tolerant manifest shape shared by [`KiloProvider`](crate::providers::kilo::KiloProvider),
+/// [`VercelProvider`](crate::providers::vercel::VercelProvider), and
+/// [`CustomDirectoryProvider`](crate::providers::custom::CustomDirectoryProvider)
+///
+/// `author`, `tags`, `homepage`, `repository`, `license`, and
+/// `requirements` are [`Inheritable`] -- a manifest may set any of them to
+/// `{ workspace = true }` to defer to the source directory's
+/// `alltheskills.toml` instead of repeating the value in every skill; see
+/// [`crate::workspace`].
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SkillManifest {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub author: Inheritable<Option<String>>,
+    #[serde(default)]
+    pub tags: Inheritable<Vec<String>>,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub repository: Inheritable<Option<String>>,
+    #[serde(default)]
+    pub homepage: Inheritable<Option<String>>,
+    #[serde(default)]
+    pub license: Inheritable<Option<String>>,
+    #[serde(default)]
+    pub requirements: Inheritable<Vec<String>>,
+}
+
+impl SkillManifest {
+    /// Deserializes `content` as JSON
+    pub fn from_json(content: &str) -> Result<Self> {
+        serde_json::from_str(content).map_err(|e| Error::Parse {
+            message: format!("invalid skill manifest: {e}"),
+        })
+    }
+
+    /// Deserializes `content` as YAML
+    pub fn from_yaml(content: &str) -> Result<Self> {
+        serde_yaml::from_str(content).map_err(|e| Error::Parse {
+            message: format!("invalid skill manifest: {e}"),
+        })
+    }
+}
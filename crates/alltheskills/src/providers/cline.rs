@@ -2,7 +2,8 @@ use crate::types::{Skill, SkillFormat, SkillMetadata, SkillSource, SourceConfig,
 use crate::utils::copy_skill_dir;
 use crate::{Error, Result};
 use async_trait::async_trait;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// Provider for Cline skills
 ///
@@ -133,6 +134,7 @@ impl ClineProvider {
         } else {
             Vec::new()
         };
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
@@ -141,7 +143,7 @@ impl ClineProvider {
             version,
             source: SkillSource::Local { path: path.clone() },
             source_type: SourceType::Cline,
-            path: path.clone(),
+            path,
             installed_at: chrono::Utc::now(),
             metadata: SkillMetadata {
                 author,
@@ -171,6 +173,7 @@ impl ClineProvider {
             .ok()
             .and_then(|content| content.lines().next().map(|s| s.to_string()))
             .unwrap_or_else(|| "Cline custom instructions".to_string());
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
@@ -197,6 +200,7 @@ impl ClineProvider {
             .and_then(|n| n.to_str())
             .unwrap_or_default()
             .to_string();
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
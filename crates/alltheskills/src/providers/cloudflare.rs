@@ -1,7 +1,8 @@
 use crate::types::{Skill, SkillFormat, SkillMetadata, SkillSource, SourceConfig, SourceType};
 use crate::{Error, Result};
 use async_trait::async_trait;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 pub struct CloudflareProvider;
 
@@ -104,6 +105,7 @@ impl CloudflareProvider {
         } else {
             "Cloudflare Workers AI skill".to_string()
         };
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
@@ -150,6 +152,7 @@ impl CloudflareProvider {
             .and_then(|v| v.as_str())
             .unwrap_or("Cloudflare Workers AI skill")
             .to_string();
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
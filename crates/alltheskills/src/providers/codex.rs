@@ -2,7 +2,8 @@ use crate::types::{Skill, SkillFormat, SkillMetadata, SkillSource, SourceConfig,
 use crate::utils::copy_skill_dir;
 use crate::{Error, Result};
 use async_trait::async_trait;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// Provider for OpenAI Codex skills
 ///
@@ -81,27 +82,114 @@ impl crate::providers::SkillProvider for CodexProvider {
     }
 
     async fn install(&self, source: SkillSource, target: PathBuf) -> Result<Skill> {
-        let source_path = match &source {
-            SkillSource::Local { path } => path.clone(),
-            _ => {
-                return Err(Error::Install {
-                    reason: "OpenAI Codex provider only supports local installation".to_string(),
-                })
+        match &source {
+            SkillSource::Local { path } => {
+                let source_path = path.clone();
+                std::fs::create_dir_all(&target)?;
+                copy_skill_dir(&source_path, &target)?;
+
+                self.parse_skill_dir(target.clone())
+                    .await?
+                    .ok_or_else(|| Error::Install {
+                        reason: "Failed to parse installed OpenAI Codex skill".to_string(),
+                    })
+            }
+            SkillSource::GitHub {
+                owner,
+                repo,
+                subdir,
+                branch,
+                auth_token,
+                ssh,
+                ..
+            } => {
+                self.install_from_github(
+                    owner,
+                    repo,
+                    subdir.as_deref(),
+                    branch.as_deref(),
+                    auth_token.as_deref(),
+                    *ssh,
+                    target,
+                )
+                .await
             }
+            _ => Err(Error::Install {
+                reason: "OpenAI Codex provider only supports local and GitHub installation"
+                    .to_string(),
+            }),
+        }
+    }
+}
+
+impl CodexProvider {
+    /// Clones `owner/repo` to a scratch directory, copies `subdir` (or the
+    /// whole repo, if unset) into `target`, and records the origin and
+    /// resolved commit SHA in the parsed skill's metadata so `info` can
+    /// show where it came from and `install` of the same source again is
+    /// reproducible
+    async fn install_from_github(
+        &self,
+        owner: &str,
+        repo: &str,
+        subdir: Option<&str>,
+        branch: Option<&str>,
+        auth_token: Option<&str>,
+        ssh: bool,
+        target: PathBuf,
+    ) -> Result<Skill> {
+        let repo_url = if ssh {
+            format!("git@github.com:{owner}/{repo}.git")
+        } else {
+            format!("https://github.com/{owner}/{repo}.git")
+        };
+
+        let nonce = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        let clone_dir = std::env::temp_dir().join(format!(
+            "alltheskills-codex-{owner}-{repo}-{}-{nonce}",
+            std::process::id()
+        ));
+
+        crate::providers::github::clone_repo(&repo_url, &clone_dir, branch, auth_token, false)?;
+
+        let resolved_ref = (|| -> std::result::Result<String, git2::Error> {
+            let repo = git2::Repository::open(&clone_dir)?;
+            Ok(repo.head()?.peel_to_commit()?.id().to_string())
+        })()
+        .ok();
+
+        let skill_source_dir = match subdir {
+            Some(sub) => clone_dir.join(sub),
+            None => clone_dir.clone(),
         };
 
         std::fs::create_dir_all(&target)?;
-        copy_skill_dir(&source_path, &target)?;
+        let copy_result = copy_skill_tree(&skill_source_dir, &target);
+        let _ = std::fs::remove_dir_all(&clone_dir);
+        copy_result?;
 
-        self.parse_skill_dir(target.clone())
-            .await?
-            .ok_or_else(|| Error::Install {
-                reason: "Failed to parse installed OpenAI Codex skill".to_string(),
-            })
+        let mut skill = self.parse_skill_dir(target.clone()).await?.ok_or_else(|| Error::Install {
+            reason: "Failed to parse installed OpenAI Codex skill".to_string(),
+        })?;
+
+        skill.source = SkillSource::GitHub {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            subdir: subdir.map(str::to_string),
+            branch: branch.map(str::to_string),
+            version_req: None,
+            auth_token: auth_token.map(str::to_string),
+            ssh,
+        };
+        skill.metadata.repository = Some(format!("https://github.com/{owner}/{repo}"));
+        skill.metadata.resolved_ref = resolved_ref;
+
+        Ok(skill)
     }
-}
 
-impl CodexProvider {
     async fn parse_skill_dir(&self, path: PathBuf) -> Result<Option<Skill>> {
         // Look for codex.json or instructions.md file
         let json_path = path.join("codex.json");
@@ -142,13 +230,19 @@ impl CodexProvider {
         if let Some(model) = config["model"].as_str() {
             tags.push(format!("model:{}", model));
         }
+
+        // Map each declared tool into the common permission model, in
+        // addition to the `tool:` tag kept for backwards compatibility
+        let mut permissions = Vec::new();
         if let Some(tools) = config["tools"].as_array() {
             for tool in tools {
                 if let Some(tool_name) = tool.as_str() {
                     tags.push(format!("tool:{}", tool_name));
+                    permissions.push(crate::types::Permission::Tool(tool_name.to_string()));
                 }
             }
         }
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
@@ -157,11 +251,12 @@ impl CodexProvider {
             version,
             source: SkillSource::Local { path: path.clone() },
             source_type: SourceType::OpenAICodex,
-            path: path.clone(),
+            path,
             installed_at: chrono::Utc::now(),
             metadata: SkillMetadata {
                 author,
                 tags,
+                permissions,
                 ..Default::default()
             },
             format: SkillFormat::CodexSkill,
@@ -191,6 +286,7 @@ impl CodexProvider {
                     .map(|s| s.trim().to_string())
             })
             .unwrap_or_else(|| "OpenAI Codex skill".to_string());
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
@@ -217,6 +313,7 @@ impl CodexProvider {
             .and_then(|n| n.to_str())
             .unwrap_or_default()
             .to_string();
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
@@ -234,3 +331,23 @@ impl CodexProvider {
         Ok(Some(skill))
     }
 }
+
+/// Copies `src` into `dst`, skipping `.git` -- used when installing a skill
+/// out of a freshly cloned repository, where [`copy_skill_dir`] would
+/// otherwise also copy the clone's git history into the installed skill
+fn copy_skill_tree(src: &Path, dst: &Path) -> Result<()> {
+    std::fs::create_dir_all(dst)?;
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        if entry.file_name() == ".git" {
+            continue;
+        }
+        let dest = dst.join(entry.file_name());
+        if entry.path().is_dir() {
+            copy_skill_tree(&entry.path(), &dest)?;
+        } else {
+            std::fs::copy(entry.path(), &dest)?;
+        }
+    }
+    Ok(())
+}
@@ -2,7 +2,8 @@ use crate::types::{Skill, SkillFormat, SkillMetadata, SkillSource, SourceConfig,
 use crate::utils::copy_skill_dir;
 use crate::{Error, Result};
 use async_trait::async_trait;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// Provider for Cursor editor skills
 ///
@@ -116,6 +117,42 @@ impl crate::providers::SkillProvider for CursorProvider {
                 reason: "Failed to parse installed Cursor skill".to_string(),
             })
     }
+
+    async fn validate(&self, skill: &Skill) -> Vec<crate::providers::Diagnostic> {
+        use crate::providers::Diagnostic;
+
+        let mut diagnostics = Vec::new();
+        let rules_path = skill.path.join(".cursorrules");
+
+        if skill.format == SkillFormat::CursorRules && !rules_path.exists() && !skill.path.is_file() {
+            diagnostics.push(Diagnostic::error(
+                ".cursorrules",
+                "expected a `.cursorrules` file but none was found",
+            ));
+        } else {
+            let content_path = if skill.path.is_file() {
+                skill.path.clone()
+            } else {
+                rules_path
+            };
+
+            match std::fs::read_to_string(&content_path) {
+                Ok(content) if content.trim().is_empty() => {
+                    diagnostics.push(Diagnostic::warning(
+                        content_path.display().to_string(),
+                        "`.cursorrules` file is empty",
+                    ));
+                }
+                Err(e) => diagnostics.push(Diagnostic::error(
+                    content_path.display().to_string(),
+                    format!("could not read rules file: {e}"),
+                )),
+                Ok(_) => {}
+            }
+        }
+
+        diagnostics
+    }
 }
 
 impl CursorProvider {
@@ -159,6 +196,7 @@ impl CursorProvider {
                     })
             })
             .unwrap_or_else(|| "Cursor custom rules".to_string());
+        let path: Arc<Path> = Arc::from(path);
 
         // Determine if it's project-level or global
         let is_project_level = path.to_string_lossy().contains("/.cursorrules")
@@ -202,6 +240,7 @@ impl CursorProvider {
             .as_str()
             .unwrap_or("Cursor configuration")
             .to_string();
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
@@ -229,6 +268,7 @@ impl CursorProvider {
             .and_then(|n| n.to_str())
             .unwrap_or_default()
             .to_string();
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
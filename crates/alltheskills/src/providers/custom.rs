@@ -0,0 +1,215 @@
+//! Config-driven provider for [`SourceType::Custom`] sources that don't
+//! warrant dedicated Rust code
+//!
+//! Before this, onboarding a new AI tool meant either hard-coding its
+//! layout into [`LocalProvider`](super::LocalProvider) (only good for
+//! `source_type = Local`), or writing a provider like
+//! [`VercelProvider`](super::VercelProvider) and
+//! registering it under a fixed key in [`register_builtin_providers`](super::register_builtin_providers) --
+//! both require a code change and a new release. [`CustomDirectoryProvider`]
+//! is instead built at runtime, one instance per [`SourceConfig::custom`],
+//! from just a directory (`SourceConfig::path`/`pattern`, exactly like
+//! [`LocalProvider`](super::LocalProvider)), the manifest filenames to look
+//! for, and the [`SkillFormat`] to tag results with -- see
+//! [`ProviderRegistry::build_from_config`](super::ProviderRegistry::build_from_config),
+//! which falls back to constructing one whenever a `Custom` source has no
+//! factory registered under its name.
+//!
+//! Manifests are parsed with [`crate::manifest::SkillManifest`], so both
+//! JSON and YAML filenames are understood without per-source code.
+
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::manifest::SkillManifest;
+use crate::types::{
+    CustomSourceConfig, Skill, SkillFormat, SkillMetadata, SkillSource, SourceConfig, SourceType,
+};
+use crate::utils::copy_skill_dir;
+use crate::{Error, Result};
+
+pub struct CustomDirectoryProvider {
+    // `SkillProvider::name` returns `&'static str`; the name is only known
+    // at config-load time, so it's leaked once per provider instance
+    // rather than widening the trait for this one implementer.
+    name: &'static str,
+    manifest_filenames: Vec<String>,
+    format: SkillFormat,
+}
+
+impl CustomDirectoryProvider {
+    /// Builds a provider for the custom source named `name`, scanning for
+    /// the manifest filenames and tagging results with the format declared
+    /// in `custom`
+    pub fn new(name: String, custom: CustomSourceConfig) -> Self {
+        Self {
+            name: Box::leak(name.into_boxed_str()),
+            manifest_filenames: custom.manifest_filenames,
+            format: custom.format,
+        }
+    }
+
+    /// Finds the first file directly under `dir` whose name matches one of
+    /// [`Self::manifest_filenames`], tried in declared order
+    fn find_manifest(&self, dir: &Path) -> Option<PathBuf> {
+        let entries: Vec<_> = std::fs::read_dir(dir).ok()?.flatten().collect();
+
+        self.manifest_filenames.iter().find_map(|pattern| {
+            entries
+                .iter()
+                .find(|entry| {
+                    entry
+                        .file_name()
+                        .to_str()
+                        .is_some_and(|file_name| crate::utils::glob_match(pattern, file_name))
+                })
+                .map(|entry| entry.path())
+        })
+    }
+
+    async fn parse_skill_dir(
+        &self,
+        path: PathBuf,
+        workspace: Option<&crate::workspace::WorkspaceManifest>,
+    ) -> Result<Option<Skill>> {
+        let Some(manifest_path) = self.find_manifest(&path) else {
+            return Ok(None);
+        };
+
+        let content = std::fs::read_to_string(&manifest_path)?;
+        let is_yaml = matches!(
+            manifest_path.extension().and_then(|e| e.to_str()),
+            Some("yaml") | Some("yml")
+        );
+        let manifest = if is_yaml {
+            SkillManifest::from_yaml(&content)?
+        } else {
+            SkillManifest::from_json(&content)?
+        };
+
+        let dir_name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default()
+            .to_string();
+        let name = manifest.name.unwrap_or_else(|| dir_name.clone());
+
+        let author = manifest.author.resolve(workspace.and_then(|w| w.author.as_ref()), "author")?;
+        let tags = manifest.tags.resolve(workspace.map(|w| &w.tags), "tags")?;
+        let repository = manifest
+            .repository
+            .resolve(workspace.and_then(|w| w.repository.as_ref()), "repository")?;
+
+        let path: Arc<Path> = Arc::from(path);
+
+        Ok(Some(Skill {
+            id: name.to_lowercase().replace(' ', "-"),
+            name,
+            description: manifest.description.unwrap_or_default(),
+            version: manifest.version,
+            source: SkillSource::Local { path: path.clone() },
+            source_type: SourceType::Custom(self.name.to_string()),
+            path,
+            installed_at: chrono::Utc::now(),
+            metadata: SkillMetadata {
+                author,
+                tags,
+                repository,
+                ..Default::default()
+            },
+            format: self.format.clone(),
+        }))
+    }
+}
+
+#[async_trait]
+impl crate::providers::SkillProvider for CustomDirectoryProvider {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn source_type(&self) -> SourceType {
+        SourceType::Custom(self.name.to_string())
+    }
+
+    fn can_handle(&self, source: &SkillSource) -> bool {
+        matches!(source, SkillSource::Local { .. })
+    }
+
+    async fn list_skills(&self, config: &SourceConfig) -> Result<Vec<Skill>> {
+        let is_our_type = matches!(&config.source_type, SourceType::Custom(name) if name == self.name);
+        if !is_our_type {
+            return Ok(vec![]);
+        }
+
+        let Some(root) = &config.path else {
+            return Ok(vec![]);
+        };
+
+        let mut skills = Vec::new();
+        let workspace = crate::workspace::WorkspaceManifest::load(root)?;
+
+        match &config.pattern {
+            Some(pattern) => {
+                for dir in crate::utils::walk_matching(root, pattern) {
+                    if let Some(skill) = self.parse_skill_dir(dir, workspace.as_ref()).await? {
+                        skills.push(skill);
+                    }
+                }
+            }
+            None => {
+                if let Ok(entries) = std::fs::read_dir(root) {
+                    for entry in entries.flatten() {
+                        if entry.path().is_dir()
+                            && let Some(skill) = self.parse_skill_dir(entry.path(), workspace.as_ref()).await?
+                        {
+                            skills.push(skill);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(skills)
+    }
+
+    async fn read_skill(&self, skill: &Skill) -> Result<String> {
+        let readme_path = skill.path.join("README.md");
+        if readme_path.exists() {
+            return std::fs::read_to_string(&readme_path).map_err(Error::from);
+        }
+
+        self.find_manifest(&skill.path)
+            .map(std::fs::read_to_string)
+            .transpose()
+            .map_err(Error::from)?
+            .ok_or_else(|| Error::NotFound {
+                name: skill.name.clone(),
+            })
+    }
+
+    async fn install(&self, source: SkillSource, target: PathBuf) -> Result<Skill> {
+        let source_path = match &source {
+            SkillSource::Local { path } => path.clone(),
+            _ => {
+                return Err(Error::Install {
+                    reason: format!("{} provider only supports local installation", self.name),
+                })
+            }
+        };
+
+        std::fs::create_dir_all(&target)?;
+        copy_skill_dir(&source_path, &target)?;
+
+        let workspace = match source_path.parent() {
+            Some(parent) => crate::workspace::WorkspaceManifest::load(parent)?,
+            None => None,
+        };
+        self.parse_skill_dir(target.clone(), workspace.as_ref())
+            .await?
+            .ok_or_else(|| Error::Install {
+                reason: format!("failed to parse installed {} skill", self.name),
+            })
+    }
+}
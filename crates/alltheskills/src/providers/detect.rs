@@ -21,6 +21,15 @@
 //! | Vercel | `VERCEL_SKILLS_DIR` | `~/.vercel/ai/skills` |
 //! | Cloudflare | `CLOUDFLARE_SKILLS_DIR` | `~/.cloudflare/workers/skills` |
 //!
+//! The "Default Path" column is a dotfile path under the home directory,
+//! but it isn't resolved literally on every platform: before trying it,
+//! each detector also tries the equivalent subpath under the OS's config
+//! directory (via the [`dirs`] crate) -- `$XDG_CONFIG_HOME` on Linux,
+//! `~/Library/Application Support` on macOS, `%APPDATA%` on Windows --
+//! and the home directory itself is resolved with `dirs::home_dir()`
+//! (`%USERPROFILE%` on Windows) rather than the `HOME` environment
+//! variable, so detection works the same off Linux as on it.
+//!
 //! # Example
 //!
 //! ```rust
@@ -39,6 +48,21 @@
 
 use std::path::PathBuf;
 
+use crate::types::SourceType;
+
+/// A skill source location found by [`KnownSources::detect_all`]
+#[derive(Debug, Clone)]
+pub struct DetectedSource {
+    /// Which provider this candidate location belongs to
+    pub source_type: SourceType,
+    /// Resolved, but not necessarily existing, path
+    pub path: PathBuf,
+    /// Whether `path` actually exists on disk
+    pub exists: bool,
+    /// Cheap entry count inside `path` (0 if it doesn't exist)
+    pub skill_count: usize,
+}
+
 /// Utility struct for detecting skill directories
 ///
 /// Provides static methods to detect the installation directories for
@@ -124,18 +148,7 @@ impl KnownSources {
         if let Ok(val) = std::env::var("CLAWDBOT_SKILLS_DIR") {
             return Some(PathBuf::from(val));
         }
-        // Check paths
-        if let Ok(home) = std::env::var("HOME") {
-            let moltbot_path = format!("{}/.moltbot/skills", home);
-            if PathBuf::from(&moltbot_path).exists() {
-                return Some(PathBuf::from(moltbot_path));
-            }
-            let clawdbot_path = format!("{}/.clawdbot/skills", home);
-            if PathBuf::from(&clawdbot_path).exists() {
-                return Some(PathBuf::from(clawdbot_path));
-            }
-        }
-        None
+        Self::resolve_fallback(["~/.moltbot/skills", "~/.clawdbot/skills"])
     }
 
     /// Detects Cursor rules directory
@@ -146,27 +159,112 @@ impl KnownSources {
         Self::detect_path("CURSOR_RULES_DIR", ["~/.cursor/rules", "~/.cursor"])
     }
 
+    /// Scans every known provider's candidate location and reports what it
+    /// found, like a single "which agents are installed here" pass instead
+    /// of calling each `*_skills_dir` accessor by hand.
+    ///
+    /// Only locations that resolve to a path at all (env var override or an
+    /// existing fallback) are included; each entry reports whether the path
+    /// actually exists and a cheap count of entries inside it.
+    pub fn detect_all() -> Vec<DetectedSource> {
+        let candidates: Vec<(SourceType, Option<PathBuf>)> = vec![
+            (SourceType::Claude, Self::claude_skills_dir()),
+            (SourceType::Cline, Self::cline_skills_dir()),
+            (SourceType::Cursor, Self::cursor_rules_dir()),
+            (SourceType::OpenClaw, Self::openclaw_skills_dir()),
+            (SourceType::RooCode, Self::roo_skills_dir()),
+            (SourceType::KiloCode, Self::kilo_skills_dir()),
+            (SourceType::OpenAICodex, Self::codex_skills_dir()),
+            (SourceType::Custom("vercel".to_string()), Self::vercel_skills_dir()),
+            (
+                SourceType::Custom("cloudflare".to_string()),
+                Self::cloudflare_skills_dir(),
+            ),
+            (SourceType::Moltbot, Self::moltbot_skills_dir()),
+        ];
+
+        candidates
+            .into_iter()
+            .filter_map(|(source_type, path)| {
+                let path = path?;
+                let exists = path.is_dir();
+                let skill_count = if exists {
+                    std::fs::read_dir(&path).map(|entries| entries.count()).unwrap_or(0)
+                } else {
+                    0
+                };
+
+                Some(DetectedSource {
+                    source_type,
+                    path,
+                    exists,
+                    skill_count,
+                })
+            })
+            .collect()
+    }
+
+    /// Detects the directory external plugin executables are loaded from
+    ///
+    /// Checks `ALLTHESKILLS_PLUGINS_DIR` env var, then `~/.alltheskills/plugins`.
+    /// Unlike the other `*_skills_dir` helpers this does not require the
+    /// directory to already exist, since callers create it on first use.
+    pub fn plugins_dir() -> PathBuf {
+        if let Ok(val) = std::env::var("ALLTHESKILLS_PLUGINS_DIR") {
+            return PathBuf::from(val);
+        }
+
+        if let Some(home) = dirs::home_dir() {
+            return home.join(".alltheskills").join("plugins");
+        }
+
+        PathBuf::from(".alltheskills/plugins")
+    }
+
     /// Generic path detection helper
     ///
-    /// First checks the environment variable, then expands and checks
-    /// each fallback path (supporting `~` for home directory).
+    /// First checks the environment variable, then resolves each
+    /// `~/.foo/bar`-style fallback via [`Self::resolve_fallback`].
     fn detect_path(
         env_key: &str,
         fallbacks: impl IntoIterator<Item = &'static str>,
     ) -> Option<PathBuf> {
-        // Check environment variable first
         if let Ok(val) = std::env::var(env_key) {
             return Some(PathBuf::from(val));
         }
 
-        // Try home directory expansion for fallbacks
-        if let Ok(home) = std::env::var("HOME") {
-            for fallback in fallbacks {
-                if let Some(path) = fallback.strip_prefix("~/") {
-                    let expanded = format!("{}/{}", home, path);
-                    if PathBuf::from(&expanded).exists() {
-                        return Some(PathBuf::from(expanded));
-                    }
+        Self::resolve_fallback(fallbacks)
+    }
+
+    /// Resolves the first existing `~/.foo/bar`-style fallback path,
+    /// platform-aware rather than `HOME`-only
+    ///
+    /// For each fallback, tries the equivalent subpath under
+    /// `dirs::config_dir()` first -- `$XDG_CONFIG_HOME` on Linux,
+    /// `~/Library/Application Support` on macOS, `%APPDATA%` on Windows --
+    /// then falls back to the literal dotfile path under
+    /// `dirs::home_dir()` (`%USERPROFILE%` on Windows), which is how every
+    /// one of these directories was created before this platform-aware
+    /// resolution existed, and remains correct on systems without a
+    /// separate config directory (e.g. a minimal container).
+    fn resolve_fallback(fallbacks: impl IntoIterator<Item = &'static str>) -> Option<PathBuf> {
+        let config_dir = dirs::config_dir();
+        let home_dir = dirs::home_dir();
+
+        for fallback in fallbacks {
+            let Some(relative) = fallback.strip_prefix("~/.") else {
+                continue;
+            };
+
+            if let Some(candidate) = config_dir.as_ref().map(|dir| dir.join(relative)) {
+                if candidate.exists() {
+                    return Some(candidate);
+                }
+            }
+
+            if let Some(candidate) = home_dir.as_ref().map(|dir| dir.join(format!(".{relative}"))) {
+                if candidate.exists() {
+                    return Some(candidate);
                 }
             }
         }
@@ -0,0 +1,459 @@
+//! GitHub repository provider
+//!
+//! Installs a skill by cloning its GitHub repository (optionally pinned
+//! to a branch -- see `update`, in the CLI crate, for how an
+//! already-installed clone is tracked against `version_req` afterwards).
+//! Supports both authenticated HTTPS (a token from
+//! [`SkillSource::GitHub::auth_token`] or the `GITHUB_TOKEN`/`GIT_TOKEN`
+//! environment variables) and SSH (agent, then a default key under
+//! `~/.ssh`), so private repositories install the same way public ones do.
+
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::types::{GitHubSourceConfig, Skill, SkillFormat, SkillMetadata, SkillSource, SourceConfig, SourceType};
+use crate::{Error, Result};
+
+/// Repository markers this provider recognizes as "this repo publishes a
+/// skill", checked via the contents API without a full clone
+const SKILL_MARKERS: [&str; 3] = ["claude.json", "SKILL.md", "skill.json"];
+
+pub struct GitHubProvider;
+
+#[async_trait]
+impl crate::providers::SkillProvider for GitHubProvider {
+    fn name(&self) -> &'static str {
+        "GitHub"
+    }
+
+    fn source_type(&self) -> SourceType {
+        SourceType::GitHub
+    }
+
+    fn can_handle(&self, source: &SkillSource) -> bool {
+        matches!(source, SkillSource::GitHub { .. })
+    }
+
+    async fn list_skills(&self, config: &SourceConfig) -> Result<Vec<Skill>> {
+        match &config.github {
+            Some(github) if github.login.is_some() => discover_org_skills(github).await,
+            _ => Ok(vec![]),
+        }
+    }
+
+    async fn read_skill(&self, skill: &Skill) -> Result<String> {
+        let readme_path = skill.path.join("README.md");
+        std::fs::read_to_string(&readme_path).map_err(Error::from)
+    }
+
+    async fn install(&self, source: SkillSource, target: PathBuf) -> Result<Skill> {
+        let SkillSource::GitHub {
+            owner,
+            repo,
+            subdir,
+            branch,
+            version_req: _,
+            auth_token,
+            ssh,
+        } = source.clone()
+        else {
+            return Err(Error::Install {
+                reason: "Invalid source type for GitHub provider".to_string(),
+            });
+        };
+
+        let repo_url = if ssh {
+            format!("git@github.com:{owner}/{repo}.git")
+        } else {
+            format!("https://github.com/{owner}/{repo}.git")
+        };
+
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let shallow = std::env::var("ALLTHESKILLS_GITHUB_SHALLOW").is_ok();
+        clone_with_mirror_cache(&repo_url, &owner, &repo, &target, branch.as_deref(), auth_token.as_deref(), shallow)?;
+
+        let skill_path: Arc<Path> = Arc::from(match &subdir {
+            Some(sub) => target.join(sub),
+            None => target.clone(),
+        });
+
+        self.parse_skill_dir(skill_path, &owner, &repo, source)
+    }
+}
+
+impl GitHubProvider {
+    fn parse_skill_dir(&self, path: Arc<Path>, owner: &str, repo: &str, source: SkillSource) -> Result<Skill> {
+        let json_path = path.join("claude.json");
+        let md_path = path.join("README.md");
+
+        let (name, description, version, format) = if json_path.exists() {
+            let content = std::fs::read_to_string(&json_path)?;
+            let config: serde_json::Value = serde_json::from_str(&content)?;
+            (
+                config["name"].as_str().unwrap_or(repo).to_string(),
+                config["description"].as_str().unwrap_or_default().to_string(),
+                config["version"].as_str().map(|s| s.to_string()),
+                SkillFormat::ClaudeSkill,
+            )
+        } else if md_path.exists() {
+            (
+                repo.to_string(),
+                format!("GitHub skill from {owner}/{repo}"),
+                None,
+                SkillFormat::GenericMarkdown,
+            )
+        } else {
+            return Err(Error::Install {
+                reason: "Failed to parse installed skill".to_string(),
+            });
+        };
+
+        Ok(Skill {
+            id: format!("{owner}-{repo}").to_lowercase().replace(' ', "-"),
+            name,
+            description,
+            version,
+            source,
+            source_type: SourceType::GitHub,
+            path,
+            installed_at: chrono::Utc::now(),
+            metadata: SkillMetadata {
+                repository: Some(format!("https://github.com/{owner}/{repo}")),
+                ..Default::default()
+            },
+            format,
+        })
+    }
+}
+
+/// Clones `url` into `target`, checking out `branch` if given, using
+/// [`remote_callbacks`] for authentication
+///
+/// `pub(crate)` so other providers that also install from a git remote
+/// (e.g. [`crate::providers::codex::CodexProvider`]) can share it instead
+/// of reimplementing the clone/auth dance. `shallow` requests a
+/// depth-1 clone, skipping the rest of the repo's history -- cheaper when
+/// only the latest tree is needed, but unusable as a source for
+/// [`clone_with_mirror_cache`]'s mirror refresh (a shallow mirror
+/// couldn't serve a different branch later).
+pub(crate) fn clone_repo(url: &str, target: &Path, branch: Option<&str>, auth_token: Option<&str>, shallow: bool) -> Result<()> {
+    let mut fetch_options = git2::FetchOptions::new();
+    fetch_options.remote_callbacks(remote_callbacks(auth_token.map(str::to_string)));
+    if shallow {
+        fetch_options.depth(1);
+    }
+
+    let mut builder = git2::build::RepoBuilder::new();
+    builder.fetch_options(fetch_options);
+    if let Some(branch) = branch {
+        builder.branch(branch);
+    }
+
+    builder.clone(url, target)?;
+    Ok(())
+}
+
+/// Longest a cached mirror is reused before [`clone_with_mirror_cache`]
+/// fetches from the network again
+const MIRROR_CACHE_TTL_SECS: i64 = 300;
+
+/// Root directory under which per-`owner/repo` mirror clones are kept, so
+/// installing the same repository more than once in a short window (e.g.
+/// several skills from one mono-repo) only hits the network the first
+/// time
+fn mirror_cache_root() -> PathBuf {
+    std::env::temp_dir().join("alltheskills-github-mirror-cache")
+}
+
+/// Path to the bare mirror clone and its freshness stamp for `owner/repo`
+fn mirror_paths(owner: &str, repo: &str) -> (PathBuf, PathBuf) {
+    let root = mirror_cache_root();
+    let key = crate::utils::sanitize_filename(&format!("{owner}-{repo}"));
+    (root.join(format!("{key}.git")), root.join(format!("{key}.stamp")))
+}
+
+/// Whether the mirror at `stamp_path` was refreshed within
+/// [`MIRROR_CACHE_TTL_SECS`]
+fn mirror_is_fresh(stamp_path: &Path) -> bool {
+    std::fs::read_to_string(stamp_path)
+        .ok()
+        .and_then(|s| s.trim().parse::<i64>().ok())
+        .is_some_and(|fetched_at| chrono::Utc::now().timestamp() - fetched_at < MIRROR_CACHE_TTL_SECS)
+}
+
+/// Clones `url` into `target`, transparently reusing a local mirror cache
+/// when one is fresh (or when `ALLTHESKILLS_GITHUB_OFFLINE` is set) to
+/// avoid re-hitting the network for a repository that was just cloned
+///
+/// Strategy: if the mirror for `owner/repo` is fresh, clone `target`
+/// straight from it (a local clone, so libgit2 hardlinks objects instead
+/// of transferring them again) -- falling back to the network on any
+/// failure, e.g. `branch` not being present in that mirror. Otherwise (or
+/// after a fallback) clones `target` from `url` as usual, then, unless
+/// `shallow`, refreshes the mirror from `target` as a bare repo so later
+/// installs of the same
+/// repository can reuse it. `ALLTHESKILLS_GITHUB_OFFLINE` skips the
+/// network clone entirely and errors if no mirror is cached.
+fn clone_with_mirror_cache(
+    url: &str,
+    owner: &str,
+    repo: &str,
+    target: &Path,
+    branch: Option<&str>,
+    auth_token: Option<&str>,
+    shallow: bool,
+) -> Result<()> {
+    let (mirror_path, stamp_path) = mirror_paths(owner, repo);
+    let offline = std::env::var("ALLTHESKILLS_GITHUB_OFFLINE").is_ok();
+
+    if (offline || mirror_is_fresh(&stamp_path)) && mirror_path.exists() {
+        let mirror_url = mirror_path.to_string_lossy().into_owned();
+        if clone_repo(&mirror_url, target, branch, None, shallow).is_ok() {
+            return Ok(());
+        }
+        if offline {
+            return Err(Error::Install {
+                reason: format!("offline mode requested but no usable cached clone of {owner}/{repo} for branch {branch:?}"),
+            });
+        }
+    } else if offline {
+        return Err(Error::Install {
+            reason: format!("offline mode requested but {owner}/{repo} has never been cloned"),
+        });
+    }
+
+    clone_repo(url, target, branch, auth_token, shallow)?;
+    if !shallow {
+        refresh_mirror(target, &mirror_path, &stamp_path);
+    }
+    Ok(())
+}
+
+/// Refreshes the `owner/repo` mirror by re-creating it as a bare clone of
+/// `target` (cheap: a local clone) and touching its freshness stamp;
+/// failures are swallowed since the mirror is purely an optimization --
+/// `target` is already a fully valid, independent clone either way
+fn refresh_mirror(target: &Path, mirror_path: &Path, stamp_path: &Path) {
+    let _ = std::fs::remove_dir_all(mirror_path);
+    if let Some(parent) = mirror_path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+
+    let mut builder = git2::build::RepoBuilder::new();
+    builder.bare(true);
+    if builder.clone(&target.to_string_lossy(), mirror_path).is_ok() {
+        let _ = std::fs::write(stamp_path, chrono::Utc::now().timestamp().to_string());
+    }
+}
+
+/// Builds `RemoteCallbacks` that try, in order: an SSH agent identity, a
+/// default SSH key under `~/.ssh`, HTTPS token auth via `auth_token` or
+/// the `GITHUB_TOKEN`/`GIT_TOKEN` environment variables, and finally
+/// `git2`'s default credential (`Cred::default`, used for NTLM/Negotiate
+/// authentication)
+fn remote_callbacks(auth_token: Option<String>) -> git2::RemoteCallbacks<'static> {
+    let mut callbacks = git2::RemoteCallbacks::new();
+
+    callbacks.credentials(move |_url, username_from_url, allowed| {
+        let username = username_from_url.unwrap_or("git");
+
+        if allowed.contains(git2::CredentialType::SSH_KEY) {
+            if let Ok(cred) = git2::Cred::ssh_key_from_agent(username) {
+                return Ok(cred);
+            }
+
+            if let Some(key_path) = default_ssh_key() {
+                let passphrase = std::env::var("SSH_KEY_PASSPHRASE").ok();
+                if let Ok(cred) = git2::Cred::ssh_key(username, None, &key_path, passphrase.as_deref()) {
+                    return Ok(cred);
+                }
+            }
+        }
+
+        if allowed.contains(git2::CredentialType::USER_PASS_PLAINTEXT) {
+            let token = auth_token
+                .clone()
+                .or_else(|| std::env::var("GITHUB_TOKEN").ok())
+                .or_else(|| std::env::var("GIT_TOKEN").ok());
+            if let Some(token) = token {
+                return git2::Cred::userpass_plaintext("x-access-token", &token);
+            }
+        }
+
+        git2::Cred::default()
+    });
+
+    callbacks
+}
+
+/// Finds the first existing default SSH private key under `~/.ssh`
+fn default_ssh_key() -> Option<PathBuf> {
+    let ssh_dir = dirs::home_dir()?.join(".ssh");
+    ["id_ed25519", "id_ecdsa", "id_rsa"]
+        .into_iter()
+        .map(|name| ssh_dir.join(name))
+        .find(|path| path.exists())
+}
+
+/// A repository entry as returned by the GitHub `.../repos` list endpoints
+#[derive(serde::Deserialize)]
+struct GitHubRepo {
+    name: String,
+    owner: GitHubRepoOwner,
+    default_branch: Option<String>,
+    #[serde(default)]
+    topics: Vec<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct GitHubRepoOwner {
+    login: String,
+}
+
+/// A directory entry as returned by the GitHub contents API
+#[derive(serde::Deserialize)]
+struct GitHubContentEntry {
+    name: String,
+}
+
+/// Enumerates every repository under `github.login` (trying it as an
+/// organization first, then as a user), keeps the ones matching
+/// `github`'s topic/name filters, and probes each surviving repo's root
+/// via the contents API for a recognized [`SKILL_MARKERS`] file --
+/// without cloning anything
+async fn discover_org_skills(github: &GitHubSourceConfig) -> Result<Vec<Skill>> {
+    let login = github.login.as_deref().unwrap_or_default();
+    let client = http_client(github.auth_token.as_deref())?;
+    let repos = fetch_repos(&client, login).await?;
+
+    let mut skills = Vec::new();
+    for repo in repos {
+        if let Some(topic) = &github.topic_filter {
+            if !repo.topics.iter().any(|t| t == topic) {
+                continue;
+            }
+        }
+        if let Some(name_filter) = &github.name_filter {
+            if !repo.name.contains(name_filter.as_str()) {
+                continue;
+            }
+        }
+
+        if has_skill_marker(&client, &repo.owner.login, &repo.name).await? {
+            skills.push(Skill {
+                id: format!("{}-{}", repo.owner.login, repo.name).to_lowercase().replace(' ', "-"),
+                name: repo.name.clone(),
+                description: format!("GitHub skill from {}/{}", repo.owner.login, repo.name),
+                version: None,
+                source: SkillSource::GitHub {
+                    owner: repo.owner.login.clone(),
+                    repo: repo.name.clone(),
+                    subdir: None,
+                    branch: repo.default_branch,
+                    version_req: None,
+                    auth_token: github.auth_token.clone(),
+                    ssh: false,
+                },
+                source_type: SourceType::GitHub,
+                path: Arc::from(Path::new("")),
+                installed_at: chrono::Utc::now(),
+                metadata: SkillMetadata {
+                    repository: Some(format!("https://github.com/{}/{}", repo.owner.login, repo.name)),
+                    ..Default::default()
+                },
+                format: SkillFormat::GenericMarkdown,
+            });
+        }
+    }
+
+    Ok(skills)
+}
+
+/// Paginates `GET /orgs/{login}/repos`, falling back to `GET
+/// /users/{login}/repos` if `login` isn't an organization
+async fn fetch_repos(client: &reqwest::Client, login: &str) -> Result<Vec<GitHubRepo>> {
+    let mut repos = Vec::new();
+    let mut page = 1u32;
+    let mut base_url = format!("https://api.github.com/orgs/{login}/repos");
+
+    loop {
+        let response = client
+            .get(&base_url)
+            .query(&[("per_page", "100"), ("page", page.to_string().as_str())])
+            .send()
+            .await
+            .map_err(api_error)?;
+
+        if page == 1 && response.status() == reqwest::StatusCode::NOT_FOUND {
+            base_url = format!("https://api.github.com/users/{login}/repos");
+            continue;
+        }
+
+        if !response.status().is_success() {
+            return Err(Error::Http {
+                status: Some(response.status().as_u16()),
+                message: format!("GitHub API request for {login}'s repositories failed"),
+            });
+        }
+
+        let batch: Vec<GitHubRepo> = response.json().await.map_err(api_error)?;
+        if batch.is_empty() {
+            break;
+        }
+        page += 1;
+        repos.extend(batch);
+    }
+
+    Ok(repos)
+}
+
+/// Checks whether `owner/repo`'s root directory contains a recognized
+/// skill marker file, via the contents API
+async fn has_skill_marker(client: &reqwest::Client, owner: &str, repo: &str) -> Result<bool> {
+    let url = format!("https://api.github.com/repos/{owner}/{repo}/contents");
+    let response = client.get(&url).send().await.map_err(api_error)?;
+    if !response.status().is_success() {
+        return Ok(false);
+    }
+
+    let entries: Vec<GitHubContentEntry> = response.json().await.map_err(api_error)?;
+    Ok(entries.iter().any(|entry| SKILL_MARKERS.contains(&entry.name.as_str())))
+}
+
+/// Builds a `reqwest::Client` carrying the headers every GitHub API call
+/// needs: a `User-Agent` (required by the API), the `vnd.github+json`
+/// accept header, and a bearer token if `auth_token` is set
+fn http_client(auth_token: Option<&str>) -> Result<reqwest::Client> {
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(reqwest::header::USER_AGENT, reqwest::header::HeaderValue::from_static("alltheskills"));
+    headers.insert(
+        reqwest::header::ACCEPT,
+        reqwest::header::HeaderValue::from_static("application/vnd.github+json"),
+    );
+
+    if let Some(token) = auth_token {
+        let mut value = reqwest::header::HeaderValue::from_str(&format!("Bearer {token}"))
+            .map_err(|e| Error::Config { message: format!("invalid GitHub auth token: {e}") })?;
+        value.set_sensitive(true);
+        headers.insert(reqwest::header::AUTHORIZATION, value);
+    }
+
+    reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .map_err(api_error)
+}
+
+/// Wraps a `reqwest::Error` as a crate [`Error`], preserving its HTTP
+/// status (if any) so callers like [`crate::core::retry`] can tell a
+/// transient failure (429, 5xx) from a permanent one
+fn api_error(source: reqwest::Error) -> Error {
+    Error::Http {
+        status: source.status().map(|s| s.as_u16()),
+        message: format!("GitHub API error: {source}"),
+    }
+}
@@ -2,7 +2,8 @@ use crate::types::{Skill, SkillFormat, SkillMetadata, SkillSource, SourceConfig,
 use crate::utils::copy_skill_dir;
 use crate::{Error, Result};
 use async_trait::async_trait;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// Provider for Kilo Code skills
 ///
@@ -45,11 +46,12 @@ impl crate::providers::SkillProvider for KiloProvider {
         };
 
         let mut skills = Vec::new();
+        let workspace = crate::workspace::WorkspaceManifest::load(&path)?;
 
         if let Ok(entries) = std::fs::read_dir(&path) {
             for entry in entries.flatten() {
                 if entry.path().is_dir()
-                    && let Some(skill) = self.parse_skill_dir(entry.path()).await?
+                    && let Some(skill) = self.parse_skill_dir(entry.path(), workspace.as_ref()).await?
                 {
                     skills.push(skill);
                 }
@@ -99,7 +101,11 @@ impl crate::providers::SkillProvider for KiloProvider {
         std::fs::create_dir_all(&target)?;
         copy_skill_dir(&source_path, &target)?;
 
-        self.parse_skill_dir(target.clone())
+        let workspace = match source_path.parent() {
+            Some(parent) => crate::workspace::WorkspaceManifest::load(parent)?,
+            None => None,
+        };
+        self.parse_skill_dir(target.clone(), workspace.as_ref())
             .await?
             .ok_or_else(|| Error::Install {
                 reason: "Failed to parse installed Kilo Code skill".to_string(),
@@ -108,7 +114,11 @@ impl crate::providers::SkillProvider for KiloProvider {
 }
 
 impl KiloProvider {
-    async fn parse_skill_dir(&self, path: PathBuf) -> Result<Option<Skill>> {
+    async fn parse_skill_dir(
+        &self,
+        path: PathBuf,
+        workspace: Option<&crate::workspace::WorkspaceManifest>,
+    ) -> Result<Option<Skill>> {
         // Look for kilo.yaml, kilo.yml, or instructions.md file
         let yaml_path = path.join("kilo.yaml");
         let yml_path = path.join("kilo.yml");
@@ -116,9 +126,9 @@ impl KiloProvider {
         let readme_path = path.join("README.md");
 
         if yaml_path.exists() {
-            self.parse_kilo_yaml(path, yaml_path).await
+            self.parse_kilo_yaml(path, yaml_path, workspace).await
         } else if yml_path.exists() {
-            self.parse_kilo_yaml(path, yml_path).await
+            self.parse_kilo_yaml(path, yml_path, workspace).await
         } else if instructions_path.exists() {
             self.parse_instructions_md(path, instructions_path).await
         } else if readme_path.exists() {
@@ -132,37 +142,21 @@ impl KiloProvider {
         &self,
         path: PathBuf,
         yaml_path: PathBuf,
+        workspace: Option<&crate::workspace::WorkspaceManifest>,
     ) -> Result<Option<Skill>> {
         let content = std::fs::read_to_string(&yaml_path)?;
-        let config: serde_yaml::Value = serde_yaml::from_str(&content).map_err(|e| Error::Parse {
-            message: format!("Failed to parse kilo.yaml: {}", e),
-        })?;
+        let manifest = crate::manifest::SkillManifest::from_yaml(&content)?;
 
-        let name = config["name"]
-            .as_str()
-            .unwrap_or_default()
-            .to_string();
-        let description = config["description"]
-            .as_str()
-            .unwrap_or_default()
-            .to_string();
-        let version = config["version"].as_str().map(|s| s.to_string());
-        let author = config["author"].as_str().map(|s| s.to_string());
-
-        // Extract tags from config
-        let mut tags = Vec::new();
-        if let Some(tags_array) = config["tags"].as_sequence() {
-            for tag in tags_array {
-                if let Some(tag_str) = tag.as_str() {
-                    tags.push(tag_str.to_string());
-                }
-            }
-        }
+        let name = manifest.name.unwrap_or_default();
+        let description = manifest.description.unwrap_or_default();
+        let version = manifest.version;
+        let author = manifest.author.resolve(workspace.and_then(|w| w.author.as_ref()), "author")?;
 
-        // Extract language for tags
-        if let Some(language) = config["language"].as_str() {
+        let mut tags = manifest.tags.resolve(workspace.map(|w| &w.tags), "tags")?;
+        if let Some(language) = &manifest.language {
             tags.push(format!("lang:{}", language));
         }
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
@@ -171,7 +165,7 @@ impl KiloProvider {
             version,
             source: SkillSource::Local { path: path.clone() },
             source_type: SourceType::KiloCode,
-            path: path.clone(),
+            path,
             installed_at: chrono::Utc::now(),
             metadata: SkillMetadata {
                 author,
@@ -205,6 +199,7 @@ impl KiloProvider {
                     .map(|s| s.trim().to_string())
             })
             .unwrap_or_else(|| "Kilo Code skill".to_string());
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
@@ -231,6 +226,7 @@ impl KiloProvider {
             .and_then(|n| n.to_str())
             .unwrap_or_default()
             .to_string();
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use crate::types::{Skill, SkillFormat, SourceType, SkillSource, SkillMetadata};
 use crate::{Result, Error};
 
@@ -20,21 +21,38 @@ impl crate::providers::SkillProvider for LocalProvider {
     }
 
     async fn list_skills(&self, config: &crate::types::SourceConfig) -> Result<Vec<Skill>> {
-        let path = match &config.source_type {
-            SourceType::Local => std::env::current_dir()?,
-            _ => return Ok(vec![]),
+        if config.source_type != SourceType::Local {
+            return Ok(vec![]);
+        }
+        let root = match &config.path {
+            Some(path) => path.clone(),
+            None => std::env::current_dir()?,
         };
 
         let mut skills = Vec::new();
-
-        if let Ok(entries) = std::fs::read_dir(path) {
-            for entry in entries.flatten() {
-                if entry.path().is_dir() {
-                    if let Some(skill) = self.parse_skill_dir(entry.path()).await? {
+        let workspace = crate::workspace::WorkspaceManifest::load(&root)?;
+
+        match &config.pattern {
+            Some(pattern) => {
+                for dir in crate::utils::walk_matching(&root, pattern) {
+                    if crate::utils::is_skill_dir(&dir)
+                        && let Some(skill) = self.parse_skill_dir(dir, workspace.as_ref()).await?
+                    {
                         skills.push(skill);
                     }
                 }
             }
+            None => {
+                if let Ok(entries) = std::fs::read_dir(&root) {
+                    for entry in entries.flatten() {
+                        if entry.path().is_dir() {
+                            if let Some(skill) = self.parse_skill_dir(entry.path(), workspace.as_ref()).await? {
+                                skills.push(skill);
+                            }
+                        }
+                    }
+                }
+            }
         }
 
         Ok(skills)
@@ -66,18 +84,55 @@ impl crate::providers::SkillProvider for LocalProvider {
         }
 
         // Parse the installed skill
-        self.parse_skill_dir(target.clone()).await?
+        let workspace = match source_path.parent() {
+            Some(parent) => crate::workspace::WorkspaceManifest::load(parent)?,
+            None => None,
+        };
+        self.parse_skill_dir(target.clone(), workspace.as_ref()).await?
             .ok_or_else(|| Error::Install { reason: "Failed to parse installed skill".to_string() })
     }
+
+    fn scan_key(&self, config: &crate::types::SourceConfig) -> Option<String> {
+        if config.source_type != SourceType::Local {
+            return None;
+        }
+        let root = match &config.path {
+            Some(path) => path.clone(),
+            None => std::env::current_dir().ok()?,
+        };
+        let root_mtime = std::fs::metadata(&root).ok()?.modified().ok()?;
+
+        match &config.pattern {
+            Some(pattern) => {
+                let mut max_mtime = root_mtime;
+                let mut count = 0usize;
+                for dir in crate::utils::walk_matching(&root, pattern) {
+                    if !crate::utils::is_skill_dir(&dir) {
+                        continue;
+                    }
+                    count += 1;
+                    if let Ok(mtime) = std::fs::metadata(&dir).and_then(|m| m.modified()) {
+                        max_mtime = max_mtime.max(mtime);
+                    }
+                }
+                Some(format!("{root_mtime:?}:{count}:{max_mtime:?}"))
+            }
+            None => Some(format!("{root_mtime:?}")),
+        }
+    }
 }
 
 impl LocalProvider {
-    async fn parse_skill_dir(&self, path: PathBuf) -> Result<Option<Skill>> {
+    async fn parse_skill_dir(
+        &self,
+        path: PathBuf,
+        workspace: Option<&crate::workspace::WorkspaceManifest>,
+    ) -> Result<Option<Skill>> {
         let json_path = path.join("claude.json");
         let md_path = path.join("README.md");
 
         if json_path.exists() {
-            self.parse_json(path, json_path).await
+            self.parse_json(path, json_path, workspace).await
         } else if md_path.exists() {
             self.parse_markdown(path).await
         } else {
@@ -85,9 +140,30 @@ impl LocalProvider {
         }
     }
 
-    async fn parse_json(&self, path: PathBuf, json_path: PathBuf) -> Result<Option<Skill>> {
+    async fn parse_json(
+        &self,
+        path: PathBuf,
+        json_path: PathBuf,
+        workspace: Option<&crate::workspace::WorkspaceManifest>,
+    ) -> Result<Option<Skill>> {
         let content = std::fs::read_to_string(&json_path)?;
         let config: serde_json::Value = serde_json::from_str(&content)?;
+        let path: Arc<Path> = Arc::from(path);
+
+        // `author`/`tags` may be a literal value or `{ "workspace": true }`
+        // deferring to the source directory's `alltheskills.toml`; a
+        // missing field deserializes as if it were absent from the
+        // manifest entirely, same as before `Inheritable` existed
+        let author: crate::workspace::Inheritable<Option<String>> = if config["author"].is_null() {
+            crate::workspace::Inheritable::Value(None)
+        } else {
+            serde_json::from_value(config["author"].clone())?
+        };
+        let tags: crate::workspace::Inheritable<Vec<String>> = if config["tags"].is_null() {
+            crate::workspace::Inheritable::Value(Vec::new())
+        } else {
+            serde_json::from_value(config["tags"].clone())?
+        };
 
         let skill = Skill {
             id: config["name"].as_str().unwrap_or_default().to_string()
@@ -97,12 +173,11 @@ impl LocalProvider {
             version: config["version"].as_str().map(|s| s.to_string()),
             source: SkillSource::Local { path: path.clone() },
             source_type: SourceType::Local,
-            path: path.clone(),
+            path,
             installed_at: chrono::Utc::now(),
             metadata: SkillMetadata {
-                author: config["author"].as_str().map(|s| s.to_string()),
-                tags: config["tags"].as_array().cloned().unwrap_or_default()
-                    .iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect(),
+                author: author.resolve(workspace.and_then(|w| w.author.as_ref()), "author")?,
+                tags: tags.resolve(workspace.map(|w| &w.tags), "tags")?,
                 ..Default::default()
             },
             format: SkillFormat::GenericJson,
@@ -116,6 +191,7 @@ impl LocalProvider {
             .and_then(|n| n.to_str())
             .unwrap_or_default()
             .to_string();
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
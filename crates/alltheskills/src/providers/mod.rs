@@ -8,18 +8,24 @@ pub mod cline;
 pub mod cloudflare;
 pub mod codex;
 pub mod cursor;
+pub mod custom;
 pub mod detect;
 pub mod github;
 pub mod kilo;
 pub mod local;
 pub mod moltbot;
+pub mod oci;
 pub mod openclaw;
+pub mod plugin;
+pub mod registry;
 pub mod roo;
+pub mod skill_index;
 pub mod trait_;
 pub mod vercel;
 
-pub use detect::KnownSources;
-pub use trait_::SkillProvider;
+pub use detect::{DetectedSource, KnownSources};
+pub use registry::{register_builtin_providers, ProviderRegistry};
+pub use trait_::{Diagnostic, DiagnosticSeverity, SkillProvider};
 
 // Re-export provider structs for convenience
 pub use claude::ClaudeProvider;
@@ -27,10 +33,14 @@ pub use cline::ClineProvider;
 pub use cloudflare::CloudflareProvider;
 pub use codex::CodexProvider;
 pub use cursor::CursorProvider;
+pub use custom::CustomDirectoryProvider;
 pub use github::GitHubProvider;
 pub use kilo::KiloProvider;
 pub use local::LocalProvider;
 pub use moltbot::MoltbotProvider;
+pub use oci::OciProvider;
 pub use openclaw::OpenClawProvider;
+pub use plugin::PluginProvider;
 pub use roo::RooProvider;
+pub use skill_index::RegistryProvider;
 pub use vercel::VercelProvider;
@@ -1,7 +1,8 @@
 use crate::types::{Skill, SkillFormat, SkillMetadata, SkillSource, SourceConfig, SourceType};
 use crate::{Error, Result};
 use async_trait::async_trait;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// Provider for Moltbot (formerly ClawdBot) skills
 ///
@@ -105,6 +106,69 @@ impl crate::providers::SkillProvider for MoltbotProvider {
             reason: "Install not yet implemented for Moltbot provider".to_string(),
         })
     }
+
+    async fn validate(&self, skill: &Skill) -> Vec<crate::providers::Diagnostic> {
+        use crate::providers::Diagnostic;
+
+        let mut diagnostics = Vec::new();
+        let manifest_path = skill.path.join("manifest.json");
+
+        if !manifest_path.exists() {
+            diagnostics.push(Diagnostic::error(
+                "manifest.json",
+                "expected a `manifest.json` but none was found",
+            ));
+            return diagnostics;
+        }
+
+        let content = match std::fs::read_to_string(&manifest_path) {
+            Ok(content) => content,
+            Err(e) => {
+                diagnostics.push(Diagnostic::error(
+                    "manifest.json",
+                    format!("could not read manifest: {e}"),
+                ));
+                return diagnostics;
+            }
+        };
+
+        let manifest: serde_json::Value = match serde_json::from_str(&content) {
+            Ok(value) => value,
+            Err(e) => {
+                diagnostics.push(Diagnostic::error(
+                    "manifest.json",
+                    format!("malformed JSON: {e}"),
+                ));
+                return diagnostics;
+            }
+        };
+
+        match manifest.get("commands").and_then(|c| c.as_array()) {
+            Some(commands) => {
+                for (i, command) in commands.iter().enumerate() {
+                    if command.get("name").and_then(|n| n.as_str()).is_none() {
+                        diagnostics.push(Diagnostic::error(
+                            "manifest.json",
+                            format!("commands[{i}] is missing a `name`"),
+                        ));
+                    }
+                }
+            }
+            None => diagnostics.push(Diagnostic::warning(
+                "manifest.json",
+                "manifest has no `commands` array",
+            )),
+        }
+
+        if !skill.path.join("SKILL.md").exists() {
+            diagnostics.push(Diagnostic::warning(
+                "SKILL.md",
+                "no SKILL.md found alongside manifest.json",
+            ));
+        }
+
+        diagnostics
+    }
 }
 
 impl MoltbotProvider {
@@ -148,6 +212,7 @@ impl MoltbotProvider {
                 }
             }
         }
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
@@ -156,7 +221,7 @@ impl MoltbotProvider {
             version,
             source: SkillSource::Local { path: path.clone() },
             source_type: SourceType::Moltbot,
-            path: path.clone(),
+            path,
             installed_at: chrono::Utc::now(),
             metadata: SkillMetadata {
                 author,
@@ -186,6 +251,7 @@ impl MoltbotProvider {
                     .map(|s| s.trim().to_string())
             })
             .unwrap_or_else(|| "Moltbot skill".to_string());
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
@@ -212,6 +278,7 @@ impl MoltbotProvider {
             .and_then(|n| n.to_str())
             .unwrap_or_default()
             .to_string();
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
@@ -0,0 +1,276 @@
+//! OCI-registry skill provider
+//!
+//! Installs a skill published as an OCI artifact, tag-addressed the way a
+//! container image is (`registry/namespace/skill:version`): `install`
+//! fetches the image manifest, downloads each layer blob, verifies it
+//! against its manifest-declared digest, and unpacks it into the target
+//! directory as a gzipped tarball, the same layer format an OCI-compliant
+//! image registry already serves. Authenticates the same way
+//! [`SkillSource::Remote`] does -- a caller-supplied list of headers,
+//! typically a registry bearer token -- rather than inventing a dedicated
+//! auth scheme.
+//!
+//! Needs the `tar` and `flate2` crates to unpack a layer's gzipped tarball;
+//! neither is a dependency of this crate yet.
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::types::{Skill, SkillFormat, SkillMetadata, SkillSource, SourceConfig, SourceType};
+use crate::{Error, Result};
+
+const MANIFEST_MEDIA_TYPE: &str = "application/vnd.oci.image.manifest.v1+json";
+
+pub struct OciProvider;
+
+#[async_trait]
+impl crate::providers::SkillProvider for OciProvider {
+    fn name(&self) -> &'static str {
+        "OCI Registry"
+    }
+
+    fn source_type(&self) -> SourceType {
+        SourceType::Oci
+    }
+
+    fn can_handle(&self, source: &SkillSource) -> bool {
+        matches!(source, SkillSource::Oci { .. })
+    }
+
+    async fn list_skills(&self, config: &SourceConfig) -> Result<Vec<Skill>> {
+        let Some(oci) = &config.oci else {
+            return Ok(vec![]);
+        };
+
+        let client = http_client(&oci.headers)?;
+        let tags = fetch_tags(&client, &oci.registry, &oci.repository).await?;
+
+        Ok(tags
+            .into_iter()
+            .map(|tag| {
+                let reference = format!("{}/{}:{}", oci.registry, oci.repository, tag);
+                Skill {
+                    id: skill_id(&oci.repository, &tag),
+                    name: repository_name(&oci.repository).to_string(),
+                    description: format!("OCI skill from {reference}"),
+                    version: Some(tag.clone()),
+                    source: SkillSource::Oci {
+                        reference: reference.clone(),
+                        headers: oci.headers.clone(),
+                    },
+                    source_type: SourceType::Oci,
+                    path: Arc::from(Path::new("")),
+                    installed_at: chrono::Utc::now(),
+                    metadata: SkillMetadata {
+                        repository: Some(format!("https://{}/v2/{}", oci.registry, oci.repository)),
+                        ..Default::default()
+                    },
+                    format: SkillFormat::Unknown,
+                }
+            })
+            .collect())
+    }
+
+    async fn read_skill(&self, skill: &Skill) -> Result<String> {
+        let readme_path = skill.path.join("README.md");
+        std::fs::read_to_string(&readme_path).map_err(Error::from)
+    }
+
+    async fn install(&self, source: SkillSource, target: PathBuf) -> Result<Skill> {
+        let SkillSource::Oci { reference, headers } = source.clone() else {
+            return Err(Error::Install {
+                reason: "Invalid source type for OCI provider".to_string(),
+            });
+        };
+
+        let (registry, repository, tag) = parse_reference(&reference)?;
+        let client = http_client(&headers)?;
+
+        let manifest = fetch_manifest(&client, &registry, &repository, &tag).await?;
+
+        std::fs::create_dir_all(&target)?;
+        for layer in &manifest.layers {
+            let blob = fetch_blob(&client, &registry, &repository, &layer.digest).await?;
+            verify_blob_digest(&blob, &layer.digest)?;
+            unpack_layer(&blob, &target)?;
+        }
+
+        let content_hash = crate::core::hash_tree(&target)?;
+
+        Ok(Skill {
+            id: skill_id(&repository, &tag),
+            name: repository_name(&repository).to_string(),
+            description: format!("OCI skill from {reference}"),
+            version: Some(tag),
+            source,
+            source_type: SourceType::Oci,
+            path: Arc::from(target.as_path()),
+            installed_at: chrono::Utc::now(),
+            metadata: SkillMetadata {
+                repository: Some(format!("https://{registry}/v2/{repository}")),
+                content_hash: Some(content_hash),
+                ..Default::default()
+            },
+            format: SkillFormat::Unknown,
+        })
+    }
+}
+
+/// A layer or config descriptor within an OCI image manifest
+#[derive(serde::Deserialize)]
+struct OciDescriptor {
+    digest: String,
+}
+
+/// The subset of an OCI image manifest this provider needs: the layer
+/// blobs to download and unpack, in order
+#[derive(serde::Deserialize)]
+struct OciManifest {
+    layers: Vec<OciDescriptor>,
+}
+
+#[derive(serde::Deserialize)]
+struct OciTagsList {
+    tags: Vec<String>,
+}
+
+/// Splits a fully-qualified reference (`registry/namespace/skill:tag`)
+/// into its registry host, repository path, and tag, defaulting to the
+/// `latest` tag when none is given
+fn parse_reference(reference: &str) -> Result<(String, String, String)> {
+    let (registry, rest) = reference.split_once('/').ok_or_else(|| Error::Install {
+        reason: format!("OCI reference `{reference}` is missing a registry host"),
+    })?;
+
+    let (repository, tag) = match rest.rsplit_once(':') {
+        Some((repo, tag)) if !tag.contains('/') => (repo.to_string(), tag.to_string()),
+        _ => (rest.to_string(), "latest".to_string()),
+    };
+
+    Ok((registry.to_string(), repository, tag))
+}
+
+/// Last path segment of a repository, used as a skill's display name
+fn repository_name(repository: &str) -> &str {
+    repository.rsplit('/').next().unwrap_or(repository)
+}
+
+fn skill_id(repository: &str, tag: &str) -> String {
+    format!("{repository}-{tag}").to_lowercase().replace(['/', ' '], "-")
+}
+
+async fn fetch_manifest(client: &reqwest::Client, registry: &str, repository: &str, tag: &str) -> Result<OciManifest> {
+    let url = format!("https://{registry}/v2/{repository}/manifests/{tag}");
+    let response = client
+        .get(&url)
+        .header(reqwest::header::ACCEPT, MANIFEST_MEDIA_TYPE)
+        .send()
+        .await
+        .map_err(api_error)?;
+
+    if !response.status().is_success() {
+        return Err(Error::Http {
+            status: Some(response.status().as_u16()),
+            message: format!("OCI manifest fetch failed for {registry}/{repository}:{tag}"),
+        });
+    }
+
+    response.json().await.map_err(api_error)
+}
+
+async fn fetch_blob(client: &reqwest::Client, registry: &str, repository: &str, digest: &str) -> Result<Vec<u8>> {
+    let url = format!("https://{registry}/v2/{repository}/blobs/{digest}");
+    let response = client.get(&url).send().await.map_err(api_error)?;
+
+    if !response.status().is_success() {
+        return Err(Error::Http {
+            status: Some(response.status().as_u16()),
+            message: format!("OCI blob fetch failed for {registry}/{repository}@{digest}"),
+        });
+    }
+
+    Ok(response.bytes().await.map_err(api_error)?.to_vec())
+}
+
+async fn fetch_tags(client: &reqwest::Client, registry: &str, repository: &str) -> Result<Vec<String>> {
+    let url = format!("https://{registry}/v2/{repository}/tags/list");
+    let response = client.get(&url).send().await.map_err(api_error)?;
+
+    if !response.status().is_success() {
+        return Err(Error::Http {
+            status: Some(response.status().as_u16()),
+            message: format!("OCI tag list fetch failed for {registry}/{repository}"),
+        });
+    }
+
+    let list: OciTagsList = response.json().await.map_err(api_error)?;
+    Ok(list.tags)
+}
+
+/// Verifies `blob`'s SHA-256 matches its manifest-declared `expected_digest`
+/// (OCI's `sha256:<hex>` format) before it's trusted enough to unpack
+///
+/// `fetch_blob` has no way to know whether a compromised or MITM'd registry
+/// served something other than what the manifest promised; this is the
+/// integrity check that catches that before `unpack_layer` ever extracts
+/// the bytes onto disk.
+fn verify_blob_digest(blob: &[u8], expected_digest: &str) -> Result<()> {
+    let Some(expected_hex) = expected_digest.strip_prefix("sha256:") else {
+        return Err(Error::Install {
+            reason: format!("unsupported OCI digest algorithm: {expected_digest}"),
+        });
+    };
+
+    let mut hasher = Sha256::new();
+    hasher.update(blob);
+    let actual_hex = format!("{:x}", hasher.finalize());
+
+    if !actual_hex.eq_ignore_ascii_case(expected_hex) {
+        return Err(Error::Install {
+            reason: format!("OCI blob digest mismatch: expected {expected_digest}, got sha256:{actual_hex}"),
+        });
+    }
+
+    Ok(())
+}
+
+/// Unpacks a gzipped tar layer blob into `target`
+fn unpack_layer(blob: &[u8], target: &Path) -> Result<()> {
+    let decoder = flate2::read::GzDecoder::new(blob);
+    tar::Archive::new(decoder).unpack(target).map_err(Error::from)
+}
+
+/// Builds a `reqwest::Client` carrying a `User-Agent` plus whatever
+/// caller-supplied `headers` this source was configured with (typically a
+/// registry bearer token), the same header-list auth scheme
+/// [`SkillSource::Remote`] already uses
+fn http_client(headers: &[(String, String)]) -> Result<reqwest::Client> {
+    let mut header_map = reqwest::header::HeaderMap::new();
+    header_map.insert(reqwest::header::USER_AGENT, reqwest::header::HeaderValue::from_static("alltheskills"));
+
+    for (key, value) in headers {
+        let name = reqwest::header::HeaderName::from_bytes(key.as_bytes())
+            .map_err(|e| Error::Config { message: format!("invalid OCI header name `{key}`: {e}") })?;
+        let mut header_value = reqwest::header::HeaderValue::from_str(value)
+            .map_err(|e| Error::Config { message: format!("invalid OCI header value for `{key}`: {e}") })?;
+        header_value.set_sensitive(name == reqwest::header::AUTHORIZATION);
+        header_map.insert(name, header_value);
+    }
+
+    reqwest::Client::builder()
+        .default_headers(header_map)
+        .build()
+        .map_err(api_error)
+}
+
+/// Wraps a `reqwest::Error` as a crate [`Error`], preserving its HTTP
+/// status (if any) so callers like [`crate::core::retry`] can tell a
+/// transient failure (429, 5xx) from a permanent one
+fn api_error(source: reqwest::Error) -> Error {
+    Error::Http {
+        status: source.status().map(|s| s.as_u16()),
+        message: format!("OCI registry API error: {source}"),
+    }
+}
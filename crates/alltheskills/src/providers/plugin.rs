@@ -0,0 +1,305 @@
+//! Subprocess-based external provider plugins
+//!
+//! This module lets third parties ship a [`SkillProvider`] as a standalone
+//! executable instead of compiling it into this crate. A plugin is any
+//! executable found in the configured plugin directory (by default
+//! `~/.alltheskills/plugins/`) that speaks a tiny newline-delimited JSON-RPC
+//! protocol over its stdin/stdout, similar to how a shell discovers external
+//! subcommands.
+//!
+//! # Handshake
+//!
+//! On discovery the plugin is spawned once and sent a `describe` request:
+//!
+//! ```json
+//! {"method":"describe","params":{}}
+//! ```
+//!
+//! The plugin must reply on stdout with its name, source type, and the
+//! glob/path patterns it can handle:
+//!
+//! ```json
+//! {"result":{"name":"my-plugin","source_type":"my-ai","can_handle":["*/.my-ai/skills/*"]}}
+//! ```
+//!
+//! After that, `list_skills`, `read_skill` and `install` are each mapped to
+//! one more request/response pair over the same child process; `name`,
+//! `source_type` and `can_handle` are answered from the handshake, and the
+//! remaining [`SkillProvider`] methods use the trait's defaults. Each line
+//! written to stdin is matched by exactly one line read back from stdout.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, Command};
+use tokio::sync::Mutex;
+
+use crate::types::{Skill, SkillSource, SourceConfig, SourceType};
+use crate::{Error, Result};
+
+/// How long to wait for a plugin to complete the initial handshake
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+/// How long to wait for a response to any single JSON-RPC call
+const CALL_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Serialize)]
+struct RpcRequest<T: Serialize> {
+    method: &'static str,
+    params: T,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcResponse<T> {
+    #[serde(default)]
+    result: Option<T>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HandshakeResult {
+    name: String,
+    source_type: String,
+    #[serde(default)]
+    can_handle: Vec<String>,
+}
+
+/// A [`SkillProvider`](crate::providers::SkillProvider) backed by an external
+/// executable speaking line-delimited JSON-RPC.
+pub struct PluginProvider {
+    executable: PathBuf,
+    name: &'static str,
+    source_type: SourceType,
+    can_handle: Vec<String>,
+    child: Mutex<Child>,
+}
+
+impl PluginProvider {
+    /// Spawns `executable`, performs the `describe` handshake, and returns
+    /// the resulting provider on success.
+    pub async fn spawn(executable: impl Into<PathBuf>) -> Result<Self> {
+        let executable = executable.into();
+
+        let mut child = Command::new(&executable)
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .map_err(|e| Error::Install {
+                reason: format!("failed to spawn plugin {}: {e}", executable.display()),
+            })?;
+
+        let handshake: HandshakeResult = tokio::time::timeout(
+            HANDSHAKE_TIMEOUT,
+            call_raw(&mut child, "describe", &serde_json::json!({})),
+        )
+        .await
+        .map_err(|_| Error::Install {
+            reason: format!("plugin {} timed out during handshake", executable.display()),
+        })??;
+
+        Ok(Self {
+            name: Box::leak(handshake.name.into_boxed_str()),
+            source_type: SourceType::Custom(handshake.source_type),
+            can_handle: handshake.can_handle,
+            executable,
+            child: Mutex::new(child),
+        })
+    }
+
+    /// Scans `dir` for executables and spawns/handshakes with each one.
+    ///
+    /// Entries that fail to spawn or fail the handshake are skipped with a
+    /// warning printed to stderr rather than aborting the whole scan.
+    pub async fn discover(dir: &Path) -> Vec<Self> {
+        let mut plugins = Vec::new();
+
+        let Ok(mut entries) = tokio::fs::read_dir(dir).await else {
+            return plugins;
+        };
+
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            if !is_executable(&path) {
+                continue;
+            }
+
+            match Self::spawn(path.clone()).await {
+                Ok(plugin) => plugins.push(plugin),
+                Err(e) => eprintln!("alltheskills: skipping plugin {}: {e}", path.display()),
+            }
+        }
+
+        plugins
+    }
+
+    async fn call<P: Serialize, R: for<'de> Deserialize<'de>>(
+        &self,
+        method: &'static str,
+        params: P,
+    ) -> Result<R> {
+        let mut child = self.child.lock().await;
+        tokio::time::timeout(
+            CALL_TIMEOUT,
+            call_raw_named(&mut child, self.name, method, &params),
+        )
+        .await
+        .map_err(|_| Error::Plugin {
+            plugin: self.name.to_string(),
+            message: format!("timed out on `{method}`"),
+            exit_status: None,
+        })?
+    }
+}
+
+async fn call_raw<P: Serialize, R: for<'de> Deserialize<'de>>(
+    child: &mut Child,
+    method: &'static str,
+    params: &P,
+) -> Result<R> {
+    call_raw_named(child, "<unregistered>", method, params).await
+}
+
+async fn call_raw_named<P: Serialize, R: for<'de> Deserialize<'de>>(
+    child: &mut Child,
+    plugin: &str,
+    method: &'static str,
+    params: &P,
+) -> Result<R> {
+    let stdin = child.stdin.as_mut().ok_or_else(|| Error::Plugin {
+        plugin: plugin.to_string(),
+        message: "stdin is not piped".to_string(),
+        exit_status: None,
+    })?;
+    let stdout = child.stdout.as_mut().ok_or_else(|| Error::Plugin {
+        plugin: plugin.to_string(),
+        message: "stdout is not piped".to_string(),
+        exit_status: None,
+    })?;
+
+    let request = RpcRequest { method, params };
+    let mut line = serde_json::to_string(&request)?;
+    line.push('\n');
+    stdin
+        .write_all(line.as_bytes())
+        .await
+        .map_err(Error::from)?;
+    stdin.flush().await.map_err(Error::from)?;
+
+    let mut reader = BufReader::new(stdout);
+    let mut response_line = String::new();
+    let bytes_read = reader
+        .read_line(&mut response_line)
+        .await
+        .map_err(Error::from)?;
+
+    if bytes_read == 0 {
+        let status = child.try_wait().ok().flatten().and_then(|s| s.code());
+        return Err(Error::Plugin {
+            plugin: plugin.to_string(),
+            message: format!("exited mid-call to `{method}`"),
+            exit_status: status,
+        });
+    }
+
+    let response: RpcResponse<R> = serde_json::from_str(response_line.trim()).map_err(|e| {
+        Error::Parse {
+            message: format!("malformed JSON-RPC response from plugin `{plugin}` for `{method}`: {e}"),
+        }
+    })?;
+
+    if let Some(message) = response.error {
+        return Err(Error::Plugin {
+            plugin: plugin.to_string(),
+            message: format!("returned error for `{method}`: {message}"),
+            exit_status: None,
+        });
+    }
+
+    response.result.ok_or_else(|| Error::Parse {
+        message: format!("plugin `{plugin}` response for `{method}` had neither result nor error"),
+    })
+}
+
+fn is_executable(path: &Path) -> bool {
+    if !path.is_file() {
+        return false;
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::metadata(path)
+            .map(|m| m.permissions().mode() & 0o111 != 0)
+            .unwrap_or(false)
+    }
+
+    #[cfg(not(unix))]
+    {
+        true
+    }
+}
+
+impl Drop for PluginProvider {
+    fn drop(&mut self) {
+        if let Ok(mut child) = self.child.try_lock() {
+            let _ = child.start_kill();
+        }
+    }
+}
+
+#[async_trait]
+impl crate::providers::SkillProvider for PluginProvider {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn source_type(&self) -> SourceType {
+        self.source_type.clone()
+    }
+
+    fn can_handle(&self, source: &SkillSource) -> bool {
+        let path = match source {
+            SkillSource::Local { path } => path.to_string_lossy().to_string(),
+            SkillSource::GitHub { owner, repo, .. } => format!("{owner}/{repo}"),
+            SkillSource::Remote { url, .. } => url.clone(),
+            SkillSource::Oci { reference, .. } => reference.clone(),
+        };
+
+        self.can_handle
+            .iter()
+            .any(|pattern| glob_match(pattern, &path))
+    }
+
+    async fn list_skills(&self, config: &SourceConfig) -> Result<Vec<Skill>> {
+        self.call("list_skills", config).await
+    }
+
+    async fn read_skill(&self, skill: &Skill) -> Result<String> {
+        self.call("read_skill", skill).await
+    }
+
+    async fn install(&self, source: SkillSource, target: std::path::PathBuf) -> Result<Skill> {
+        self.call("install", serde_json::json!({ "source": source, "target": target }))
+            .await
+    }
+}
+
+/// Minimal glob matcher supporting `*` as "any run of characters".
+fn glob_match(pattern: &str, text: &str) -> bool {
+    fn inner(pattern: &[u8], text: &[u8]) -> bool {
+        match pattern.split_first() {
+            None => text.is_empty(),
+            Some((b'*', rest)) => {
+                inner(rest, text) || (!text.is_empty() && inner(pattern, &text[1..]))
+            }
+            Some((p, rest)) => {
+                !text.is_empty() && text[0] == *p && inner(rest, &text[1..])
+            }
+        }
+    }
+
+    inner(pattern.as_bytes(), text.as_bytes())
+}
@@ -0,0 +1,132 @@
+//! Config-driven provider registry
+//!
+//! Maps [`SourceType`]/[`SourceType::Custom`] keys to provider
+//! constructors, so the set of active providers -- and the order they're
+//! queried in -- is driven by [`AllSkillsConfig::sources`] instead of a
+//! fixed list of `add_provider` calls hardcoded into every CLI command.
+
+use super::SkillProvider;
+use crate::types::{AllSkillsConfig, SourceConfig, SourceType};
+use std::collections::HashMap;
+
+type ProviderFactory = Box<dyn Fn() -> Box<dyn SkillProvider> + Send + Sync>;
+
+/// Registry of provider constructors, keyed by source type
+///
+/// Built-ins are registered by [`register_builtin_providers`]; downstream
+/// crates can contribute their own via [`ProviderRegistry::register`]
+/// before calling [`ProviderRegistry::build_from_config`].
+pub struct ProviderRegistry {
+    factories: HashMap<String, ProviderFactory>,
+}
+
+impl ProviderRegistry {
+    /// Creates an empty registry with no providers registered
+    pub fn new() -> Self {
+        Self {
+            factories: HashMap::new(),
+        }
+    }
+
+    /// Registers a provider constructor under `name`, overwriting any
+    /// existing registration for that name
+    pub fn register<F>(&mut self, name: impl Into<String>, factory: F)
+    where
+        F: Fn() -> Box<dyn SkillProvider> + Send + Sync + 'static,
+    {
+        self.factories.insert(name.into(), Box::new(factory));
+    }
+
+    /// Builds providers for each enabled source in `config`, ordered by
+    /// descending `priority`. A source with no registered provider for its
+    /// type is skipped, unless it's a [`SourceType::Custom`] source that
+    /// declares [`SourceConfig::custom`] settings, in which case a
+    /// [`super::CustomDirectoryProvider`] is built for it on the fly
+    ///
+    /// Each provider is paired with the [`SourceConfig`] it was built from
+    /// -- not just looked up again later by `source_type`, which would
+    /// collapse two configs sharing a type (e.g. two GitHub orgs) onto the
+    /// same one. Pass each pair to
+    /// [`SkillReader::add_provider_for_source`](crate::SkillReader::add_provider_for_source)
+    /// to preserve that association.
+    pub fn build_from_config(&self, config: &AllSkillsConfig) -> Vec<(SourceConfig, Box<dyn SkillProvider>)> {
+        let mut sources: Vec<&SourceConfig> = config.sources.iter().filter(|s| s.enabled).collect();
+        sources.sort_by(|a, b| b.priority.cmp(&a.priority));
+
+        sources
+            .into_iter()
+            .filter_map(|source| {
+                let key = source_type_key(&source.source_type);
+                if let Some(factory) = self.factories.get(key.as_str()) {
+                    return Some((source.clone(), factory()));
+                }
+
+                // No dedicated provider registered under this name -- if the
+                // source declares its own manifest filenames/format, scan it
+                // generically instead of requiring a code change.
+                let SourceType::Custom(name) = &source.source_type else {
+                    return None;
+                };
+                let custom = source.custom.clone()?;
+                Some((
+                    source.clone(),
+                    Box::new(super::CustomDirectoryProvider::new(name.clone(), custom)) as Box<dyn SkillProvider>,
+                ))
+            })
+            .collect()
+    }
+}
+
+impl Default for ProviderRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Maps a [`SourceType`] onto the registry key used by
+/// [`register_builtin_providers`]; matches the source-type strings the
+/// CLI's `add-source --source-type` flag already accepts.
+fn source_type_key(source_type: &SourceType) -> String {
+    match source_type {
+        SourceType::Claude => "claude".to_string(),
+        SourceType::Cline => "cline".to_string(),
+        SourceType::Cursor => "cursor".to_string(),
+        SourceType::OpenClaw => "openclaw".to_string(),
+        SourceType::RooCode => "roo".to_string(),
+        SourceType::OpenAICodex => "codex".to_string(),
+        SourceType::KiloCode => "kilo".to_string(),
+        SourceType::Moltbot => "moltbot".to_string(),
+        SourceType::GitHub => "github".to_string(),
+        SourceType::Local => "local".to_string(),
+        SourceType::Registry => "registry".to_string(),
+        SourceType::Oci => "oci".to_string(),
+        SourceType::Custom(name) => name.to_lowercase(),
+    }
+}
+
+/// Registers every built-in provider under its canonical key
+///
+/// `cache_dir` is threaded through to [`super::RegistryProvider`], the one
+/// built-in that needs a place on disk to cache fetched indexes --
+/// everything else here is a stateless unit struct.
+pub fn register_builtin_providers(cache_dir: &std::path::Path) -> ProviderRegistry {
+    let mut registry = ProviderRegistry::new();
+    registry.register("claude", || Box::new(super::ClaudeProvider));
+    registry.register("cline", || Box::new(super::ClineProvider));
+    registry.register("cursor", || Box::new(super::CursorProvider));
+    registry.register("openclaw", || Box::new(super::OpenClawProvider));
+    registry.register("roo", || Box::new(super::RooProvider));
+    registry.register("codex", || Box::new(super::CodexProvider));
+    registry.register("kilo", || Box::new(super::KiloProvider));
+    registry.register("moltbot", || Box::new(super::MoltbotProvider));
+    registry.register("vercel", || Box::new(super::VercelProvider));
+    registry.register("cloudflare", || Box::new(super::CloudflareProvider));
+    registry.register("github", || Box::new(super::GitHubProvider));
+    registry.register("local", || Box::new(super::LocalProvider));
+    let cache_dir = cache_dir.to_path_buf();
+    registry.register("registry", move || {
+        Box::new(super::RegistryProvider::new(cache_dir.clone()))
+    });
+    registry.register("oci", || Box::new(super::OciProvider));
+    registry
+}
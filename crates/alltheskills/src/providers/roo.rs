@@ -1,7 +1,8 @@
 use crate::types::{Skill, SkillFormat, SkillMetadata, SkillSource, SourceConfig, SourceType};
 use crate::{Error, Result};
 use async_trait::async_trait;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// Provider for Roo Code (formerly Roo Cline) skills
 ///
@@ -117,6 +118,7 @@ impl RooProvider {
         } else {
             Vec::new()
         };
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
@@ -125,7 +127,7 @@ impl RooProvider {
             version,
             source: SkillSource::Local { path: path.clone() },
             source_type: SourceType::RooCode,
-            path: path.clone(),
+            path,
             installed_at: chrono::Utc::now(),
             metadata: SkillMetadata {
                 author,
@@ -156,6 +158,7 @@ impl RooProvider {
             .as_str()
             .unwrap_or("Roo Code custom mode")
             .to_string();
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
@@ -183,6 +186,7 @@ impl RooProvider {
             .and_then(|n| n.to_str())
             .unwrap_or_default()
             .to_string();
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
             id: name.to_lowercase().replace(" ", "-"),
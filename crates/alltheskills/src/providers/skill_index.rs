@@ -0,0 +1,187 @@
+//! Remote registry index provider
+//!
+//! Lets users discover skills they don't already know the URL for, the way
+//! a tool like navi pulls cheatsheets from a central index: [`RegistryProvider`]
+//! fetches a JSON index (a flat list of `{name, description, tags, source}`
+//! entries) from one or more configured URLs, caches each index under
+//! [`AllSkillsConfig::cache_dir`](crate::types::AllSkillsConfig::cache_dir)
+//! keyed by its own ETag and last-fetch time, and resolves `install` by
+//! delegating to whichever concrete provider handles the entry's embedded
+//! [`SkillSource`].
+//!
+//! This cache is separate from [`SkillCache`](crate::cache::SkillCache)'s
+//! generic `scan_key` mechanism: `scan_key` is synchronous and meant for
+//! cheap local checks (a directory mtime, a git HEAD), not a conditional
+//! HTTP round-trip, so the registry index keeps its own small cache file
+//! per URL instead.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::types::{Skill, SkillFormat, SkillMetadata, SkillSource, SourceConfig, SourceType};
+use crate::utils::sanitize_filename;
+use crate::{Error, Result};
+
+/// One entry in a registry index, as published by the index's JSON file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RegistryEntry {
+    name: String,
+    description: String,
+    #[serde(default)]
+    tags: Vec<String>,
+    source: SkillSource,
+}
+
+/// What's persisted to disk per registry URL: the entries from the last
+/// successful fetch, the ETag that produced them (sent back as
+/// `If-None-Match` so an unchanged index costs only a round-trip, not a
+/// download), and when that fetch happened
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct IndexCache {
+    etag: Option<String>,
+    last_fetched: i64,
+    entries: Vec<RegistryEntry>,
+}
+
+pub struct RegistryProvider {
+    cache_dir: PathBuf,
+}
+
+impl RegistryProvider {
+    /// Creates a provider that caches fetched indexes under
+    /// `cache_dir/registry/`
+    pub fn new(cache_dir: PathBuf) -> Self {
+        Self { cache_dir }
+    }
+
+    fn cache_path_for(&self, url: &str) -> PathBuf {
+        self.cache_dir
+            .join("registry")
+            .join(format!("{}.json", sanitize_filename(url)))
+    }
+
+    /// Fetches `url`'s index, honoring (and updating) the on-disk cache,
+    /// and returns its entries
+    async fn fetch_index(&self, url: &str) -> Result<Vec<RegistryEntry>> {
+        let cache_path = self.cache_path_for(url);
+        let cached: Option<IndexCache> = std::fs::read_to_string(&cache_path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok());
+
+        let client = reqwest::Client::new();
+        let mut request = client
+            .get(url)
+            .header(reqwest::header::USER_AGENT, "alltheskills");
+        if let Some(etag) = cached.as_ref().and_then(|c| c.etag.as_deref()) {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+        }
+
+        let response = request.send().await.map_err(registry_error)?;
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            if let Some(cached) = cached {
+                return Ok(cached.entries);
+            }
+        }
+
+        if !response.status().is_success() {
+            return Err(Error::Http {
+                status: Some(response.status().as_u16()),
+                message: format!("registry index fetch failed for {url}"),
+            });
+        }
+
+        let etag = response
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let entries: Vec<RegistryEntry> = response.json().await.map_err(registry_error)?;
+
+        let fresh = IndexCache {
+            etag,
+            last_fetched: chrono::Utc::now().timestamp(),
+            entries: entries.clone(),
+        };
+        if let Some(parent) = cache_path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        if let Ok(json) = serde_json::to_string(&fresh) {
+            let _ = std::fs::write(&cache_path, json);
+        }
+
+        Ok(entries)
+    }
+}
+
+#[async_trait]
+impl crate::providers::SkillProvider for RegistryProvider {
+    fn name(&self) -> &'static str {
+        "Remote Registry"
+    }
+
+    fn source_type(&self) -> SourceType {
+        SourceType::Registry
+    }
+
+    fn can_handle(&self, _source: &SkillSource) -> bool {
+        // Registry entries are never installed directly through this
+        // provider -- `install` below delegates to whichever concrete
+        // provider actually handles the entry's embedded `SkillSource`.
+        false
+    }
+
+    async fn list_skills(&self, config: &SourceConfig) -> Result<Vec<Skill>> {
+        let Some(registry) = &config.registry else {
+            return Ok(vec![]);
+        };
+
+        let mut skills = Vec::new();
+        for url in &registry.urls {
+            for entry in self.fetch_index(url).await? {
+                skills.push(Skill {
+                    id: entry.name.to_lowercase().replace(' ', "-"),
+                    name: entry.name,
+                    description: entry.description,
+                    version: None,
+                    source: entry.source,
+                    source_type: SourceType::Registry,
+                    path: Arc::from(Path::new("")),
+                    installed_at: chrono::Utc::now(),
+                    metadata: SkillMetadata {
+                        tags: entry.tags,
+                        ..Default::default()
+                    },
+                    format: SkillFormat::Unknown,
+                });
+            }
+        }
+
+        Ok(skills)
+    }
+
+    async fn read_skill(&self, skill: &Skill) -> Result<String> {
+        let readme_path = skill.path.join("README.md");
+        std::fs::read_to_string(&readme_path).map_err(Error::from)
+    }
+
+    async fn install(&self, source: SkillSource, target: PathBuf) -> Result<Skill> {
+        match source {
+            SkillSource::Local { .. } => crate::providers::LocalProvider.install(source, target).await,
+            SkillSource::GitHub { .. } => crate::providers::GitHubProvider.install(source, target).await,
+            SkillSource::Oci { .. } => crate::providers::OciProvider.install(source, target).await,
+            SkillSource::Remote { url, .. } => Err(Error::Install {
+                reason: format!("no provider installs from a bare remote URL yet: {url}"),
+            }),
+        }
+    }
+}
+
+fn registry_error(source: reqwest::Error) -> Error {
+    Error::Http {
+        status: source.status().map(|s| s.as_u16()),
+        message: format!("registry index error: {source}"),
+    }
+}
@@ -52,6 +52,51 @@
 use crate::types::{Skill, SkillSource, SourceConfig};
 use async_trait::async_trait;
 
+/// How serious a [`Diagnostic`] is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSeverity {
+    /// The skill will likely fail to load
+    Error,
+    /// Worth fixing but won't block loading
+    Warning,
+}
+
+/// A single problem a provider found while validating one of its own skills
+///
+/// Each provider knows its own required layout, so [`SkillProvider::validate`]
+/// lets it report format-specific problems (missing fields, malformed
+/// manifests, empty instruction files) rather than the generic structural
+/// checks the cross-provider lint pass already does.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    /// Severity of the problem
+    pub severity: DiagnosticSeverity,
+    /// File the problem was found in, relative to the skill's directory
+    pub file: Option<String>,
+    /// Human-readable description of the problem
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Shorthand for an error-level diagnostic
+    pub fn error(file: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: DiagnosticSeverity::Error,
+            file: Some(file.into()),
+            message: message.into(),
+        }
+    }
+
+    /// Shorthand for a warning-level diagnostic
+    pub fn warning(file: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: DiagnosticSeverity::Warning,
+            file: Some(file.into()),
+            message: message.into(),
+        }
+    }
+}
+
 /// Trait for skill providers that can discover and read skills from a source
 ///
 /// This is the core abstraction for adding support for new AI assistant platforms.
@@ -116,4 +161,45 @@ pub trait SkillProvider: Send + Sync {
         source: SkillSource,
         target: std::path::PathBuf,
     ) -> Result<Skill, crate::Error>;
+
+    /// Validates that `skill` satisfies this provider's format-specific
+    /// requirements (required fields, well-formed manifest, non-empty
+    /// instruction file, ...).
+    ///
+    /// The default implementation reports nothing; providers with a
+    /// meaningful on-disk layout to check should override it. Unlike
+    /// `list_skills`, this never fails the whole call — collect every
+    /// problem found into the returned `Vec` instead of stopping early.
+    async fn validate(&self, _skill: &Skill) -> Vec<Diagnostic> {
+        Vec::new()
+    }
+
+    /// Returns a cheap token that changes whenever `list_skills(config)`
+    /// would return different results (a directory mtime, a git HEAD SHA,
+    /// an API ETag, ...), so a cache can skip a full rescan when it's
+    /// unchanged since the last call.
+    ///
+    /// The default implementation reports no key, which tells callers the
+    /// provider has no cheap freshness check and should always be
+    /// rescanned.
+    fn scan_key(&self, _config: &SourceConfig) -> Option<String> {
+        None
+    }
+
+    /// Recomputes `skill`'s installed-tree hash and compares it against
+    /// [`skill.metadata.content_hash`](crate::types::SkillMetadata::content_hash),
+    /// detecting tampering or drift independent of whatever commit/version
+    /// a lockfile thinks is checked out.
+    ///
+    /// The default implementation hashes `skill.path` with
+    /// [`crate::core::hash_tree`] -- right for every provider in this
+    /// crate, since they all install to a plain directory on disk.
+    /// Returns `Ok(true)` when `content_hash` is unset, since a skill with
+    /// nothing recorded to verify against hasn't drifted from anything.
+    async fn verify(&self, skill: &Skill) -> Result<bool, crate::Error> {
+        let Some(expected) = &skill.metadata.content_hash else {
+            return Ok(true);
+        };
+        Ok(&crate::core::hash_tree(&skill.path)? == expected)
+    }
 }
@@ -2,7 +2,8 @@ use crate::types::{Skill, SkillFormat, SkillMetadata, SkillSource, SourceConfig,
 use crate::utils::copy_skill_dir;
 use crate::{Error, Result};
 use async_trait::async_trait;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 pub struct VercelProvider;
 
@@ -40,11 +41,12 @@ impl crate::providers::SkillProvider for VercelProvider {
         };
 
         let mut skills = Vec::new();
+        let workspace = crate::workspace::WorkspaceManifest::load(&path)?;
 
         if let Ok(entries) = std::fs::read_dir(path) {
             for entry in entries.flatten() {
                 if entry.path().is_dir()
-                    && let Some(skill) = self.parse_skill_dir(entry.path()).await?
+                    && let Some(skill) = self.parse_skill_dir(entry.path(), workspace.as_ref()).await?
                 {
                     skills.push(skill);
                 }
@@ -73,7 +75,11 @@ impl crate::providers::SkillProvider for VercelProvider {
         std::fs::create_dir_all(&target)?;
         copy_skill_dir(&source_path, &target)?;
 
-        self.parse_skill_dir(target.clone())
+        let workspace = match source_path.parent() {
+            Some(parent) => crate::workspace::WorkspaceManifest::load(parent)?,
+            None => None,
+        };
+        self.parse_skill_dir(target.clone(), workspace.as_ref())
             .await?
             .ok_or_else(|| Error::Install {
                 reason: "Failed to parse installed Vercel skill".to_string(),
@@ -82,12 +88,16 @@ impl crate::providers::SkillProvider for VercelProvider {
 }
 
 impl VercelProvider {
-    async fn parse_skill_dir(&self, path: PathBuf) -> Result<Option<Skill>> {
+    async fn parse_skill_dir(
+        &self,
+        path: PathBuf,
+        workspace: Option<&crate::workspace::WorkspaceManifest>,
+    ) -> Result<Option<Skill>> {
         let json_path = path.join("skill.json");
         let config_path = path.join("ai.config.json");
 
         if json_path.exists() {
-            self.parse_skill_json(path, json_path).await
+            self.parse_skill_json(path, json_path, workspace).await
         } else if config_path.exists() {
             self.parse_ai_config(path, config_path).await
         } else {
@@ -95,36 +105,41 @@ impl VercelProvider {
         }
     }
 
-    async fn parse_skill_json(&self, path: PathBuf, json_path: PathBuf) -> Result<Option<Skill>> {
+    async fn parse_skill_json(
+        &self,
+        path: PathBuf,
+        json_path: PathBuf,
+        workspace: Option<&crate::workspace::WorkspaceManifest>,
+    ) -> Result<Option<Skill>> {
         let content = std::fs::read_to_string(&json_path)?;
-        let config: serde_json::Value = serde_json::from_str(&content)?;
-
-        // Parse tags array safely
-        let tags: Vec<String> = config["tags"]
-            .as_array()
-            .map(|arr| {
-                arr.iter()
-                    .filter_map(|t| t.as_str().map(|s| s.to_string()))
-                    .collect()
-            })
-            .unwrap_or_default();
+        let manifest = crate::manifest::SkillManifest::from_json(&content)?;
+
+        // `id` isn't part of the shared SkillManifest (no other provider
+        // needs it), so it's still read off the raw value here
+        let raw: serde_json::Value = serde_json::from_str(&content)?;
+        let id = raw["id"].as_str().unwrap_or_default().to_string();
+
+        let author = manifest.author.resolve(workspace.and_then(|w| w.author.as_ref()), "author")?;
+        let tags = manifest.tags.resolve(workspace.map(|w| &w.tags), "tags")?;
+        let repository = manifest
+            .repository
+            .resolve(workspace.and_then(|w| w.repository.as_ref()), "repository")?;
+
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
-            id: config["id"].as_str().unwrap_or_default().to_string(),
-            name: config["name"].as_str().unwrap_or_default().to_string(),
-            description: config["description"]
-                .as_str()
-                .unwrap_or_default()
-                .to_string(),
-            version: config["version"].as_str().map(|s| s.to_string()),
+            id,
+            name: manifest.name.unwrap_or_default(),
+            description: manifest.description.unwrap_or_default(),
+            version: manifest.version,
             source: SkillSource::Local { path: path.clone() },
             source_type: SourceType::Custom("vercel".to_string()),
-            path: path.clone(),
+            path,
             installed_at: chrono::Utc::now(),
             metadata: SkillMetadata {
-                author: config["author"].as_str().map(|s| s.to_string()),
+                author,
                 tags,
-                repository: config["repository"].as_str().map(|s| s.to_string()),
+                repository,
                 ..Default::default()
             },
             format: SkillFormat::GenericJson,
@@ -135,22 +150,20 @@ impl VercelProvider {
 
     async fn parse_ai_config(&self, path: PathBuf, config_path: PathBuf) -> Result<Option<Skill>> {
         let content = std::fs::read_to_string(&config_path)?;
-        let config: serde_json::Value = serde_json::from_str(&content)?;
+        let manifest = crate::manifest::SkillManifest::from_json(&content)?;
 
-        let name = path
+        let dir_name = path
             .file_name()
             .and_then(|n| n.to_str())
             .unwrap_or_default()
             .to_string();
+        let path: Arc<Path> = Arc::from(path);
 
         let skill = Skill {
-            id: name.to_lowercase().replace(" ", "-"),
-            name: config["name"].as_str().unwrap_or(&name).to_string(),
-            description: config["description"]
-                .as_str()
-                .unwrap_or("Vercel AI skill")
-                .to_string(),
-            version: config["version"].as_str().map(|s| s.to_string()),
+            id: dir_name.to_lowercase().replace(" ", "-"),
+            name: manifest.name.unwrap_or(dir_name),
+            description: manifest.description.unwrap_or_else(|| "Vercel AI skill".to_string()),
+            version: manifest.version,
             source: SkillSource::Local { path: path.clone() },
             source_type: SourceType::Custom("vercel".to_string()),
             path,
@@ -0,0 +1,207 @@
+//! JSON Schema generation and validation for recognized manifest formats
+//!
+//! `validate_single_skill` (in the CLI) used to only check that a manifest
+//! like `codex.json` parsed as arbitrary JSON, never its structure. Each
+//! [`ManifestFormat`] here is backed by a small Rust struct describing
+//! that manifest's shape; `schemars` generates a JSON Schema from it, and
+//! [`ManifestFormat::validate`] checks a parsed manifest against that
+//! schema with `jsonschema`, returning structured errors ("missing
+//! `name`", "`tools` must be an array of strings") instead of a generic
+//! "valid JSON" line.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result};
+
+/// A manifest format alltheskills recognizes and can generate/validate a
+/// JSON Schema for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestFormat {
+    /// `codex.json` (OpenAI Codex)
+    Codex,
+    /// `claude.json` (Claude Code)
+    Claude,
+    /// `cline.json` (Cline)
+    Cline,
+    /// `cursor.json` (Cursor)
+    Cursor,
+    /// `roo.json` (Roo Code)
+    Roo,
+    /// `manifest.json` (Moltbot)
+    Moltbot,
+    /// `skill.json` (OpenClaw)
+    OpenClaw,
+}
+
+impl ManifestFormat {
+    /// Every recognized manifest format, in a stable order
+    pub fn all() -> &'static [ManifestFormat] {
+        &[
+            ManifestFormat::Codex,
+            ManifestFormat::Claude,
+            ManifestFormat::Cline,
+            ManifestFormat::Cursor,
+            ManifestFormat::Roo,
+            ManifestFormat::Moltbot,
+            ManifestFormat::OpenClaw,
+        ]
+    }
+
+    /// The manifest filename this format is recognized by
+    pub fn filename(&self) -> &'static str {
+        match self {
+            ManifestFormat::Codex => "codex.json",
+            ManifestFormat::Claude => "claude.json",
+            ManifestFormat::Cline => "cline.json",
+            ManifestFormat::Cursor => "cursor.json",
+            ManifestFormat::Roo => "roo.json",
+            ManifestFormat::Moltbot => "manifest.json",
+            ManifestFormat::OpenClaw => "skill.json",
+        }
+    }
+
+    /// Looks up the format whose [`filename`](Self::filename) matches
+    /// `name`, case-insensitively
+    pub fn from_filename(name: &str) -> Option<ManifestFormat> {
+        Self::all().iter().copied().find(|f| f.filename().eq_ignore_ascii_case(name))
+    }
+
+    /// Generates this format's JSON Schema as a [`serde_json::Value`],
+    /// suitable for `alltheskills schema <format>` or editor integration
+    pub fn schema(&self) -> serde_json::Value {
+        let root = match self {
+            ManifestFormat::Codex => schemars::schema_for!(CodexManifest),
+            ManifestFormat::Claude => schemars::schema_for!(ClaudeManifest),
+            ManifestFormat::Cline => schemars::schema_for!(ClineManifest),
+            ManifestFormat::Cursor => schemars::schema_for!(CursorManifest),
+            ManifestFormat::Roo => schemars::schema_for!(RooManifest),
+            ManifestFormat::Moltbot => schemars::schema_for!(MoltbotManifest),
+            ManifestFormat::OpenClaw => schemars::schema_for!(OpenClawManifest),
+        };
+        serde_json::to_value(root).unwrap_or(serde_json::Value::Null)
+    }
+
+    /// Validates `manifest` against this format's schema, returning one
+    /// message per structural problem found (missing required field,
+    /// wrong type, ...); an empty result means `manifest` is structurally
+    /// valid
+    pub fn validate(&self, manifest: &serde_json::Value) -> Result<Vec<String>> {
+        let schema = self.schema();
+        let compiled = jsonschema::JSONSchema::compile(&schema).map_err(|e| Error::Parse {
+            message: format!("invalid generated schema for {}: {e}", self.filename()),
+        })?;
+
+        match compiled.validate(manifest) {
+            Ok(()) => Ok(Vec::new()),
+            Err(errors) => Ok(errors.map(|e| format!("{}: {e}", e.instance_path)).collect()),
+        }
+    }
+}
+
+/// `codex.json` -- see [`crate::providers::codex::CodexProvider`]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CodexManifest {
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub model: Option<String>,
+    #[serde(default)]
+    pub tools: Vec<String>,
+}
+
+/// `claude.json` -- see [`crate::providers::local::LocalProvider`]'s
+/// `claude.json` parsing, shared with the Claude-native provider
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ClaudeManifest {
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// `cline.json` -- see [`crate::providers::cline::ClineProvider`]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ClineManifest {
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// `cursor.json` -- see [`crate::providers::cursor::CursorProvider`]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CursorManifest {
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+}
+
+/// `roo.json` -- see [`crate::providers::roo::RooProvider`]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct RooManifest {
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// `manifest.json` -- see [`crate::providers::moltbot::MoltbotProvider`]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MoltbotManifest {
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub commands: Vec<MoltbotCommand>,
+}
+
+/// One entry of a [`MoltbotManifest`]'s `commands` array
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MoltbotCommand {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// `skill.json` -- see the OpenClaw provider's manifest parsing
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct OpenClawManifest {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub repository: Option<String>,
+    #[serde(default)]
+    pub homepage: Option<String>,
+    #[serde(default)]
+    pub license: Option<String>,
+}
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
-use super::{SkillScope, SourceConfig};
+use super::{SkillScope, SkillSource, SourceConfig, SourceType};
 
 /// Global configuration for AllTheSkills
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,6 +17,118 @@ pub struct AllSkillsConfig {
     pub install_dir: PathBuf,
     /// Cache directory for temporary files
     pub cache_dir: PathBuf,
+    /// Command aliases, e.g. `co = "install --checkout"` or
+    /// `co = ["install", "--checkout"]`, expanded by the CLI before
+    /// dispatching to a built-in or external subcommand
+    #[serde(default)]
+    pub aliases: HashMap<String, AliasValue>,
+    /// Named shortcuts for `install`, e.g. `work-skills = { github = { owner
+    /// = "acme", repo = "skills", subdir = "backend" } }`, so a team can
+    /// write `alltheskills install work-skills` instead of spelling out the
+    /// full GitHub owner/repo/subdir or local path every time. Checked by
+    /// the install CLI path before falling back to its usual
+    /// `https://github.com/`/local-path parsing. Distinct from
+    /// [`Self::aliases`], which rewrites whole command lines, not install
+    /// targets.
+    #[serde(default)]
+    pub source_aliases: HashMap<String, SkillSource>,
+    /// Number of skills updated concurrently by `update`, overridable with
+    /// `--jobs`
+    #[serde(default = "default_update_jobs")]
+    pub update_jobs: usize,
+    /// Maximum attempts [`crate::core::with_retry`] makes for a
+    /// network-backed operation (cloning, GitHub API calls) before giving
+    /// up, including the first try
+    #[serde(default = "default_retry_attempts")]
+    pub retry_attempts: u32,
+    /// Base delay in milliseconds before the first retry; each
+    /// subsequent attempt doubles it (with jitter) up to a fixed cap
+    #[serde(default = "default_retry_base_delay_ms")]
+    pub retry_base_delay_ms: u64,
+}
+
+/// The value side of an `[alias]` entry, written either as a plain string
+/// (`co = "install --checkout"`, split on whitespace) or a list of already
+///-tokenized args (`co = ["install", "--checkout"]`, useful when an
+/// argument itself contains spaces)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AliasValue {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl AliasValue {
+    /// Splits this alias's value into the argument tokens it expands to
+    pub fn tokens(&self) -> Vec<String> {
+        match self {
+            AliasValue::Single(s) => s.split_whitespace().map(str::to_string).collect(),
+            AliasValue::Multiple(tokens) => tokens.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for AliasValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tokens().join(" "))
+    }
+}
+
+fn default_update_jobs() -> usize {
+    4
+}
+
+fn default_retry_attempts() -> u32 {
+    3
+}
+
+fn default_retry_base_delay_ms() -> u64 {
+    200
+}
+
+/// Enabled-by-default sources for [`AllSkillsConfig::default`], one per
+/// built-in provider in [`crate::providers::register_builtin_providers`]
+/// (excluding `github`, which is only ever added explicitly per-repo via
+/// `install`/`add-source`, and `registry`/`oci`, which list nothing
+/// without their own per-source settings). Listed highest-priority first so a fresh
+/// config queries every built-in, matching the provider list these
+/// commands used to hardcode before they became config-driven.
+fn default_sources() -> Vec<SourceConfig> {
+    let types = [
+        ("Claude Code", SourceType::Claude),
+        ("Cline", SourceType::Cline),
+        ("Cursor", SourceType::Cursor),
+        ("Roo Code", SourceType::RooCode),
+        ("OpenClaw", SourceType::OpenClaw),
+        ("Moltbot", SourceType::Moltbot),
+        ("OpenAI Codex", SourceType::OpenAICodex),
+        ("Kilo Code", SourceType::KiloCode),
+        ("Vercel AI SDK", SourceType::Custom("vercel".to_string())),
+        (
+            "Cloudflare Workers AI",
+            SourceType::Custom("cloudflare".to_string()),
+        ),
+        ("Local", SourceType::Local),
+    ];
+    let count = types.len() as i32;
+
+    types
+        .into_iter()
+        .enumerate()
+        .map(|(i, (name, source_type))| SourceConfig {
+            name: name.to_string(),
+            source_type,
+            enabled: true,
+            scope: SkillScope::User,
+            priority: count - i as i32,
+            github: None,
+            path: None,
+            pattern: None,
+            registry: None,
+            custom: None,
+            oci: None,
+        })
+        .collect()
 }
 
 impl Default for AllSkillsConfig {
@@ -23,9 +136,14 @@ impl Default for AllSkillsConfig {
         Self {
             version: 1,
             default_scope: SkillScope::User,
-            sources: Vec::new(),
+            sources: default_sources(),
             install_dir: PathBuf::from(".alltheskills"),
             cache_dir: PathBuf::from(".alltheskills/cache"),
+            aliases: HashMap::new(),
+            source_aliases: HashMap::new(),
+            update_jobs: default_update_jobs(),
+            retry_attempts: default_retry_attempts(),
+            retry_base_delay_ms: default_retry_base_delay_ms(),
         }
     }
 }
@@ -0,0 +1,57 @@
+//! Resolved dependency lockfile
+//!
+//! Distinct from [`Lockfile`](super::Lockfile), which pins each installed
+//! git-backed skill to a branch/tag and commit for `update`: this type
+//! records the *result of dependency resolution* for one skill's full
+//! transitive graph, mirroring `Cargo.lock`, so a second machine can
+//! install the identical resolved set without re-resolving any version
+//! requirement. Built by
+//! [`DependencyResolver::lock`](crate::dependencies::DependencyResolver::lock).
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// One resolved entry in a [`DependencyLock`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedDependency {
+    /// Exact version chosen for this skill
+    pub version: String,
+    /// Where this skill's content came from, e.g. `github:owner/repo`, a
+    /// filesystem path, or a URL
+    pub source: String,
+    /// [`crate::core::hash_tree`] digest of this skill's installed tree,
+    /// used by
+    /// [`DependencyResolver::verify_lock`](crate::dependencies::DependencyResolver::verify_lock)
+    /// to detect drift -- a file hand-edited on disk, not just a
+    /// version/source change -- between this entry and what's actually
+    /// installed
+    pub content_hash: String,
+    /// Names of this skill's own direct (non-optional) dependencies -- the
+    /// graph's edges
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+}
+
+/// Reproducible record of one skill's fully resolved transitive
+/// dependency graph
+///
+/// Entries are stored in a [`BTreeMap`] keyed by skill name, so
+/// serialization order is always alphabetical and re-resolving an
+/// unchanged graph produces an identical file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyLock {
+    /// Lockfile format version
+    pub version: u8,
+    /// Resolved skills, keyed by name
+    #[serde(default)]
+    pub skills: BTreeMap<String, LockedDependency>,
+}
+
+impl Default for DependencyLock {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            skills: BTreeMap::new(),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Pinned resolution record for one installed skill
+///
+/// Written to [`Lockfile`] after a successful git-backed update so the
+/// exact commit can be reproduced on another machine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockedSkill {
+    /// Resolved commit the skill is currently pinned to
+    pub commit: String,
+    /// Branch or tag that resolved to `commit`
+    pub reference: String,
+    /// Skill version at the time of resolution, if known
+    pub version: Option<String>,
+    /// Commit the skill was pinned to immediately before this resolution,
+    /// if any -- what `update --rollback <name>` restores
+    #[serde(default)]
+    pub previous_commit: Option<String>,
+    /// SHA-256 hash of the skill's installed tree at the time it was
+    /// pinned, from [`crate::core::hash_tree`], used by
+    /// [`SkillProvider::verify`](crate::providers::SkillProvider::verify)
+    /// to detect drift between what's on disk and what the lockfile
+    /// expects, independent of the commit SHA itself (e.g. a file edited
+    /// by hand after install, with no new commit to explain it)
+    #[serde(default)]
+    pub content_hash: String,
+}
+
+/// Reproducible lockfile pinning every git-backed skill to an exact commit
+///
+/// Mirrors [`AllSkillsConfig`](super::AllSkillsConfig): it lives next to
+/// `alltheskills.toml` as `alltheskills.lock`, keyed by skill id, so two
+/// machines loading the same lockfile resolve to identical skill trees.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Lockfile {
+    /// Lockfile format version
+    pub version: u8,
+    /// Locked skills, keyed by skill id
+    #[serde(default)]
+    pub skills: HashMap<String, LockedSkill>,
+}
+
+impl Default for Lockfile {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            skills: HashMap::new(),
+        }
+    }
+}
@@ -4,9 +4,15 @@
 //! the library for representing skills, sources, and configurations.
 
 pub mod config;
+pub mod dependency_lock;
+pub mod lock;
+pub mod permission;
 pub mod skill;
 pub mod source;
 
-pub use config::AllSkillsConfig;
+pub use config::{AliasValue, AllSkillsConfig};
+pub use dependency_lock::{DependencyLock, LockedDependency};
+pub use lock::{LockedSkill, Lockfile};
+pub use permission::{Capability, Permission, PermissionDef, PermissionGrant};
 pub use skill::{Skill, SkillFormat, SkillMetadata, SourceType};
-pub use source::{SkillScope, SkillSource, SourceConfig};
+pub use source::{CustomSourceConfig, GitHubSourceConfig, SkillScope, SkillSource, SourceConfig};
@@ -0,0 +1,126 @@
+//! Skill permission and capability manifest types
+//!
+//! A skill manifest (`codex.json`, `claude.json`, `.cursorrules`, `roo.json`,
+//! ...) may declare the tools it invokes, the filesystem paths it reads or
+//! writes, and the network hosts it reaches. Providers map their native
+//! manifest fields into the common [`Permission`] set during parsing so
+//! `install_skill` can show one consent summary regardless of source, and
+//! [`validate_skill`](crate) can flag a manifest that requests something
+//! unresolvable or over-broad.
+
+use serde::{Deserialize, Serialize};
+
+use super::SkillScope;
+
+/// A single capability a skill's manifest may request
+///
+/// Permissions are compared and deduped set-wise, so the same `Tool("bash")`
+/// requested by two different manifest fields collapses to one entry.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Permission {
+    /// Invoke a named tool (e.g. a Codex `tools` entry or a Claude
+    /// `allowed-tools` entry)
+    Tool(String),
+    /// Read files matching a glob, relative to the skill's own directory
+    FsRead(String),
+    /// Write files matching a glob, relative to the skill's own directory
+    FsWrite(String),
+    /// Reach a network host (a hostname, or `*` for any)
+    Net(String),
+}
+
+impl Permission {
+    /// Whether this permission's scope is unbounded -- a bare `*` or `**`
+    /// glob, or a bare `*` host -- which [`validate_skill`](crate) flags as over-broad even
+    /// though it's a well-formed request
+    pub fn is_wildcard(&self) -> bool {
+        match self {
+            Permission::FsRead(glob) | Permission::FsWrite(glob) => glob.trim() == "*" || glob.trim() == "**",
+            Permission::Net(host) => host.trim() == "*",
+            Permission::Tool(_) => false,
+        }
+    }
+}
+
+/// A named bundle of permissions a skill manifest references by id,
+/// e.g. `"shell-access"` bundling every `Tool`/`Fs*` permission a skill
+/// needs to run shell commands against its own directory
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Capability {
+    /// Identifier the capability is referenced by
+    pub id: String,
+    /// Permissions this capability grants
+    pub permissions: Vec<Permission>,
+    /// Scope this capability is allowed to be granted at (e.g. a
+    /// `Project`-scoped capability shouldn't be handed to a skill
+    /// installed globally); `None` means it isn't restricted to one
+    #[serde(default)]
+    pub scope: Option<SkillScope>,
+}
+
+/// A reusable, documented permission definition, scaffolded once via
+/// `permission new <id>` and referenced from a [`Capability`] by id
+/// instead of re-typing the same `kind:value` specs in every capability
+/// that needs it
+///
+/// Unlike [`Permission`], which is a single fine-grained grant, a
+/// `PermissionDef` groups every allow-list a related set of actions needs
+/// -- filesystem globs, shell command prefixes, network hosts -- under one
+/// stable id and a human-readable description, the unit a reviewer
+/// actually reasons about.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PermissionDef {
+    /// Stable identifier this permission is referenced by (from a
+    /// [`Capability`]'s spec list as `@<id>`)
+    pub id: String,
+    /// Human-readable explanation of what this permission is for, shown
+    /// wherever the permission is listed or a consent prompt surfaces it
+    pub description: String,
+    /// Filesystem globs the permission allows reading, relative to a
+    /// skill's own directory
+    #[serde(default)]
+    pub fs_read: Vec<String>,
+    /// Filesystem globs the permission allows writing, relative to a
+    /// skill's own directory
+    #[serde(default)]
+    pub fs_write: Vec<String>,
+    /// Shell command prefixes the permission allows invoking (e.g. `"git"`,
+    /// `"npm run"`)
+    #[serde(default)]
+    pub shell: Vec<String>,
+    /// Network hosts the permission allows reaching
+    #[serde(default)]
+    pub net: Vec<String>,
+}
+
+impl PermissionDef {
+    /// Expands this definition's allow-lists into the fine-grained
+    /// [`Permission`] values a [`PermissionGrant`]/[`Capability`] actually
+    /// stores
+    pub fn expand(&self) -> Vec<Permission> {
+        self.fs_read
+            .iter()
+            .map(|g| Permission::FsRead(g.clone()))
+            .chain(self.fs_write.iter().map(|g| Permission::FsWrite(g.clone())))
+            .chain(self.shell.iter().map(|c| Permission::Tool(c.clone())))
+            .chain(self.net.iter().map(|h| Permission::Net(h.clone())))
+            .collect()
+    }
+}
+
+/// Record of which permissions a user has already consented to for a
+/// skill, persisted alongside its metadata so a later run only re-prompts
+/// if the manifest's requested set grows
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct PermissionGrant {
+    /// Permissions the user has granted
+    pub granted: Vec<Permission>,
+}
+
+impl PermissionGrant {
+    /// Permissions in `requested` that aren't already in this grant --
+    /// what a consent prompt needs to ask about
+    pub fn ungranted<'a>(&self, requested: &'a [Permission]) -> Vec<&'a Permission> {
+        requested.iter().filter(|p| !self.granted.contains(p)).collect()
+    }
+}
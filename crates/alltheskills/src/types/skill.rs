@@ -11,7 +11,9 @@
 //! - [`SkillMetadata`] - Additional metadata about a skill
 
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
 
 use super::SkillSource;
 
@@ -34,7 +36,13 @@ pub struct Skill {
     /// Type of source (Claude, GitHub, etc.)
     pub source_type: SourceType,
     /// Local filesystem path to the skill
-    pub path: PathBuf,
+    ///
+    /// Shared via `Arc` rather than owned outright: providers hand out the
+    /// same scanned-root path to every `Skill` they parse from it, so
+    /// cloning a `Skill` (or its matching `SkillSource::Local`) only bumps
+    /// a refcount instead of reallocating the path.
+    #[serde(with = "crate::utils::arc_path")]
+    pub path: Arc<Path>,
     /// When the skill was installed
     pub installed_at: chrono::DateTime<chrono::Utc>,
     /// Additional metadata
@@ -66,6 +74,11 @@ pub enum SourceType {
     GitHub,
     /// Local filesystem
     Local,
+    /// Entry discovered from a remote registry index
+    /// (see [`crate::providers::RegistryProvider`])
+    Registry,
+    /// OCI-registry artifact (see [`crate::providers::OciProvider`])
+    Oci,
     /// Custom source type
     Custom(String),
 }
@@ -118,6 +131,37 @@ pub struct SkillMetadata {
     pub requirements: Vec<String>,
     /// Dependencies on other skills
     pub dependencies: Vec<SkillDependency>,
+    /// Named feature sets, mapping a feature name to the optional
+    /// dependency names it activates -- analogous to Cargo features,
+    /// consulted by [`DependencyResolver::resolve_with_features`](crate::dependencies::DependencyResolver::resolve_with_features)
+    #[serde(default)]
+    pub features: HashMap<String, Vec<String>>,
+    /// Tools, filesystem globs, and network hosts this skill's manifest
+    /// declares it needs, mapped from the provider's native fields (e.g.
+    /// Codex's `tools` array) into the common [`super::Permission`] set
+    #[serde(default)]
+    pub permissions: Vec<super::Permission>,
+    /// Exact revision (e.g. a commit SHA) a git/URL-backed skill was
+    /// installed from, letting `info` show what's actually on disk --
+    /// distinct from a `SkillSource::GitHub`'s `branch`/`version_req`,
+    /// which describe what to track, not what was last resolved
+    #[serde(default)]
+    pub resolved_ref: Option<String>,
+    /// Expected [`crate::core::hash_tree`] digest for this skill's
+    /// installed tree, for [`SkillProvider::verify`](crate::providers::SkillProvider::verify)
+    /// to compare against
+    ///
+    /// Set by [`OciProvider::install`](crate::providers::OciProvider) to
+    /// the hash of the tree it just unpacked; no other provider's
+    /// `list_skills`/`install` populates it -- a provider has no idea what
+    /// hash a lockfile expects. Callers that
+    /// want to verify a skill set this from the matching
+    /// [`LockedSkill::content_hash`](crate::types::LockedSkill::content_hash)
+    /// before calling `verify`, the same "caller supplies what it already
+    /// has, the library doesn't reach for it" split [`SkillReader::sync_locked`](crate::SkillReader::sync_locked)
+    /// uses for the lockfile itself.
+    #[serde(default)]
+    pub content_hash: Option<String>,
 }
 
 /// A dependency on another skill
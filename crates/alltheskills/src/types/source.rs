@@ -1,16 +1,18 @@
 //! Source types for AllTheSkills
 //!
 //! This module defines types for representing skill sources, including
-//! local filesystem paths, GitHub repositories, and remote URLs.
+//! local filesystem paths, GitHub repositories, remote URLs, and OCI
+//! registry artifacts.
 //!
 //! # Source Types
 //!
-//! - [`SkillSource`] - Location of a skill (local, GitHub, remote)
+//! - [`SkillSource`] - Location of a skill (local, GitHub, remote, OCI)
 //! - [`SourceConfig`] - Configuration for a skill source
 //! - [`SkillScope`] - Installation scope (global, user, project)
 
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use super::SourceType;
 
@@ -19,8 +21,10 @@ use super::SourceType;
 pub enum SkillSource {
     /// Local filesystem path
     Local {
-        /// Path to the skill directory
-        path: PathBuf,
+        /// Path to the skill directory, shared with the [`Skill::path`](super::Skill::path)
+        /// it was parsed into
+        #[serde(with = "crate::utils::arc_path")]
+        path: Arc<Path>,
     },
     /// GitHub repository
     GitHub {
@@ -32,6 +36,21 @@ pub enum SkillSource {
         subdir: Option<String>,
         /// Git branch (optional)
         branch: Option<String>,
+        /// Semver requirement the skill should track (e.g. `^1.0.0`),
+        /// matched against the repository's semver tags (with or without a
+        /// leading `v`) by `update`
+        /// instead of always fast-forwarding to the branch tip
+        #[serde(default)]
+        version_req: Option<String>,
+        /// Personal access token used for HTTPS authentication against a
+        /// private repository, tried before the `GITHUB_TOKEN`/`GIT_TOKEN`
+        /// environment variables
+        #[serde(default)]
+        auth_token: Option<String>,
+        /// Clone over SSH (`git@github.com:owner/repo.git`) instead of
+        /// HTTPS, for repositories only reachable that way
+        #[serde(default)]
+        ssh: bool,
     },
     /// Generic remote URL
     Remote {
@@ -40,6 +59,18 @@ pub enum SkillSource {
         /// HTTP headers to include in requests
         headers: Vec<(String, String)>,
     },
+    /// OCI-registry artifact, tag-addressed the way a container image is
+    /// (`registry/namespace/skill:version`), installed by
+    /// [`crate::providers::OciProvider`]
+    Oci {
+        /// Fully-qualified reference, e.g. `ghcr.io/myorg/my-skill:1.2.0`
+        reference: String,
+        /// HTTP headers to include in registry API requests, reusing
+        /// [`SkillSource::Remote`]'s auth pattern for a registry bearer
+        /// token instead of inventing a dedicated `auth_token` field
+        #[serde(default)]
+        headers: Vec<(String, String)>,
+    },
 }
 
 /// Configuration for a skill source
@@ -55,6 +86,94 @@ pub struct SourceConfig {
     pub scope: SkillScope,
     /// Priority for ordering (higher = earlier)
     pub priority: i32,
+    /// Organization/user discovery settings, only consulted when
+    /// `source_type` is [`SourceType::GitHub`]
+    #[serde(default)]
+    pub github: Option<GitHubSourceConfig>,
+    /// Arbitrary filesystem root this source scans for skills, honored by
+    /// providers that walk a directory tree (currently
+    /// [`SourceType::Local`] via [`crate::providers::LocalProvider`], and a
+    /// generically scanned [`SourceType::Custom`] source via
+    /// [`crate::providers::CustomDirectoryProvider`]); when unset,
+    /// `LocalProvider` scans the current directory and
+    /// `CustomDirectoryProvider` lists nothing. This
+    /// is what lets a user register a non-standard location (a monorepo's
+    /// `teams/*/skills` layout, say) without any code changes.
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+    /// Glob pattern (`*` within a path segment, `**` for any number of
+    /// segments) applied under `path` to find skill directories, e.g.
+    /// `"teams/*/skills/**"`. Unset scans `path`'s immediate children only.
+    #[serde(default)]
+    pub pattern: Option<String>,
+    /// Remote index settings, only consulted when `source_type` is
+    /// [`SourceType::Registry`]
+    #[serde(default)]
+    pub registry: Option<RegistrySourceConfig>,
+    /// Generic directory-scanning settings for a [`SourceType::Custom`]
+    /// source with no dedicated Rust provider registered under its name --
+    /// consulted by [`ProviderRegistry::build_from_config`](crate::providers::ProviderRegistry::build_from_config)
+    /// to instantiate a [`CustomDirectoryProvider`](crate::providers::CustomDirectoryProvider)
+    /// on the fly, the way `path`/`pattern` let a user point
+    /// [`SourceType::Local`] at a non-standard layout without a code change
+    #[serde(default)]
+    pub custom: Option<CustomSourceConfig>,
+    /// Tag-enumeration settings, only consulted when `source_type` is
+    /// [`SourceType::Oci`]
+    #[serde(default)]
+    pub oci: Option<OciSourceConfig>,
+}
+
+/// Manifest filenames and format for a generically scanned
+/// [`SourceType::Custom`] source
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomSourceConfig {
+    /// Manifest filenames to look for in each candidate skill directory,
+    /// tried in order and matched with the same `*` globbing as
+    /// [`SourceConfig::pattern`] (e.g. `"skill.json"`, `"*.yaml"`); the
+    /// first match found is parsed
+    pub manifest_filenames: Vec<String>,
+    /// Format to tag every skill discovered under this source with
+    pub format: super::SkillFormat,
+}
+
+/// Settings for discovering skills published in one or more remote
+/// registry indexes, consulted by
+/// [`RegistryProvider::list_skills`](crate::providers::RegistryProvider)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct RegistrySourceConfig {
+    /// URLs of the JSON indexes to fetch, each a list of
+    /// `{name, description, tags, source}` entries
+    pub urls: Vec<String>,
+}
+
+/// Settings for discovering every skill published across a GitHub
+/// organization or user account, consulted by
+/// [`GitHubProvider::list_skills`](crate::providers::GitHubProvider)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct GitHubSourceConfig {
+    /// Organization or user login to enumerate repositories for
+    pub login: Option<String>,
+    /// Personal access token for the GitHub API and any private
+    /// repositories it returns
+    pub auth_token: Option<String>,
+    /// Only include repositories tagged with this topic
+    pub topic_filter: Option<String>,
+    /// Only include repositories whose name contains this substring
+    pub name_filter: Option<String>,
+}
+
+/// Settings for enumerating tags published under one OCI repository,
+/// consulted by [`OciProvider::list_skills`](crate::providers::OciProvider)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct OciSourceConfig {
+    /// Registry host to enumerate tags against, e.g. `ghcr.io`
+    pub registry: String,
+    /// Repository path within the registry, e.g. `myorg/my-skill`
+    pub repository: String,
+    /// HTTP headers to include in registry API requests (bearer tokens, etc.)
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
 }
 
 /// Scope of a skill installation
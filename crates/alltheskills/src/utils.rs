@@ -97,6 +97,192 @@ pub fn sanitize_filename(name: &str) -> String {
         .to_string()
 }
 
+/// Computes the Levenshtein edit distance between two strings
+///
+/// Uses the standard dynamic-programming recurrence with two rolling rows,
+/// so it runs in `O(len(a) * len(b))` time and `O(min(len(a), len(b)))`
+/// space. Comparison is done byte-wise on whatever the caller passes in;
+/// callers that want case-insensitive matching should lowercase both
+/// strings first.
+pub fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let (shorter, longer) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+
+    let mut prev_row: Vec<usize> = (0..=shorter.len()).collect();
+    let mut curr_row = vec![0usize; shorter.len() + 1];
+
+    for (i, &long_byte) in longer.iter().enumerate() {
+        curr_row[0] = i + 1;
+
+        for (j, &short_byte) in shorter.iter().enumerate() {
+            let cost = if long_byte == short_byte { 0 } else { 1 };
+            curr_row[j + 1] = (prev_row[j + 1] + 1) // deletion
+                .min(curr_row[j] + 1) // insertion
+                .min(prev_row[j] + cost); // substitution
+        }
+
+        std::mem::swap(&mut prev_row, &mut curr_row);
+    }
+
+    prev_row[shorter.len()]
+}
+
+/// `serde(with = "arc_path")` support for `Arc<Path>` fields
+///
+/// `serde`'s `rc` feature only special-cases `Arc<str>`/`Arc<[T]>`, not
+/// `Arc<Path>` (`Path` is unsized and has no `Deserialize` impl of its own),
+/// so shared-path fields like [`Skill::path`](crate::types::Skill::path)
+/// route through a `PathBuf` on the wire and get wrapped back into an
+/// `Arc` on the way in.
+pub mod arc_path {
+    use serde::Deserialize;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    /// Serializes an `Arc<Path>` the same way a `PathBuf` would be
+    pub fn serialize<S>(path: &Arc<Path>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(path.as_ref(), serializer)
+    }
+
+    /// Deserializes a `PathBuf` and wraps it in an `Arc`
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Arc<Path>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let buf = PathBuf::deserialize(deserializer)?;
+        Ok(Arc::from(buf))
+    }
+}
+
+/// Minimal glob matcher supporting `*` (any characters within a single
+/// path segment) and `**` (any number of path segments, including zero),
+/// used by [`crate::providers::LocalProvider`] to honor a configured
+/// [`SourceConfig::pattern`](crate::types::SourceConfig::pattern) without
+/// pulling in the `glob` crate for something this small. Both `pattern`
+/// and `path` are split on `/`; matching is otherwise plain byte comparison.
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    let pattern_segments: Vec<&str> = pattern.split('/').filter(|s| !s.is_empty()).collect();
+    let path_segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    match_segments(&pattern_segments, &path_segments)
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.first() {
+        None => path.is_empty(),
+        Some(&"**") => {
+            match_segments(&pattern[1..], path)
+                || (!path.is_empty() && match_segments(pattern, &path[1..]))
+        }
+        Some(segment) => {
+            !path.is_empty() && match_segment(segment, path[0]) && match_segments(&pattern[1..], &path[1..])
+        }
+    }
+}
+
+/// Matches a single path segment against a pattern segment containing `*`
+/// wildcards (standard two-pointer wildcard matching, no backtracking stack)
+fn match_segment(pattern: &str, segment: &str) -> bool {
+    let (pattern, segment) = (pattern.as_bytes(), segment.as_bytes());
+    let (mut pi, mut si) = (0, 0);
+    let (mut star, mut star_si) = (None, 0);
+
+    while si < segment.len() {
+        if pi < pattern.len() && (pattern[pi] == b'*' || pattern[pi] == segment[si]) {
+            if pattern[pi] == b'*' {
+                star = Some(pi);
+                star_si = si;
+                pi += 1;
+            } else {
+                pi += 1;
+                si += 1;
+            }
+        } else if let Some(star_pi) = star {
+            pi = star_pi + 1;
+            star_si += 1;
+            si = star_si;
+        } else {
+            return false;
+        }
+    }
+
+    while pi < pattern.len() && pattern[pi] == b'*' {
+        pi += 1;
+    }
+
+    pi == pattern.len()
+}
+
+/// Maximum directory depth [`walk_matching`] descends, guarding against
+/// runaway recursion on a symlink cycle
+const WALK_MATCHING_MAX_DEPTH: usize = 12;
+
+/// Recursively finds directories under `root` whose path relative to
+/// `root` matches `pattern` (see [`glob_match`]), shared by
+/// [`crate::providers::LocalProvider`] and
+/// [`crate::providers::CustomDirectoryProvider`] to honor a configured
+/// [`SourceConfig::pattern`](crate::types::SourceConfig::pattern)
+pub fn walk_matching(root: &Path, pattern: &str) -> Vec<std::path::PathBuf> {
+    let mut matches = Vec::new();
+    walk_matching_inner(root, root, pattern, 0, &mut matches);
+    matches
+}
+
+fn walk_matching_inner(
+    root: &Path,
+    current: &Path,
+    pattern: &str,
+    depth: usize,
+    out: &mut Vec<std::path::PathBuf>,
+) {
+    if depth > WALK_MATCHING_MAX_DEPTH {
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(current) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        if let Ok(rel) = path.strip_prefix(root) {
+            let rel_str = rel.to_string_lossy().replace('\\', "/");
+            if glob_match(pattern, &rel_str) {
+                out.push(path.clone());
+            }
+        }
+
+        walk_matching_inner(root, &path, pattern, depth + 1, out);
+    }
+}
+
+/// Finds candidates close to `query` by Levenshtein distance
+///
+/// Compares lowercased strings, keeps candidates whose distance is within
+/// `max(3, query.len() / 3)`, and returns up to `limit` of them sorted by
+/// distance (closest first).
+pub fn suggest_closest<'a>(query: &str, candidates: &[&'a str], limit: usize) -> Vec<&'a str> {
+    let query_lower = query.to_lowercase();
+    let threshold = (query_lower.len() / 3).max(3);
+
+    let mut scored: Vec<(usize, &str)> = candidates
+        .iter()
+        .map(|candidate| {
+            let distance = levenshtein_distance(&query_lower, &candidate.to_lowercase());
+            (distance, *candidate)
+        })
+        .filter(|(distance, _)| *distance <= threshold)
+        .collect();
+
+    scored.sort_by_key(|(distance, _)| *distance);
+    scored.into_iter().take(limit).map(|(_, s)| s).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,4 +294,29 @@ mod tests {
         assert_eq!(sanitize_filename(".hidden"), "hidden");
         assert_eq!(sanitize_filename("normal"), "normal");
     }
+
+    #[test]
+    fn test_levenshtein_distance() {
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(levenshtein_distance("same", "same"), 0);
+        assert_eq!(levenshtein_distance("", "abc"), 3);
+        assert_eq!(levenshtein_distance("roo", "roocode"), 4);
+    }
+
+    #[test]
+    fn test_suggest_closest() {
+        let candidates = ["claude", "cline", "cursor", "roo"];
+        let suggestions = suggest_closest("clade", &candidates, 3);
+        assert_eq!(suggestions.first(), Some(&"claude"));
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("teams/*/skills", "teams/infra/skills"));
+        assert!(!glob_match("teams/*/skills", "teams/infra/backend/skills"));
+        assert!(glob_match("teams/*/skills/**", "teams/infra/skills/formatter"));
+        assert!(glob_match("teams/*/skills/**", "teams/infra/skills/nested/formatter"));
+        assert!(glob_match("**", "anything/at/all"));
+        assert!(!glob_match("teams/*/skills", "other/infra/skills"));
+    }
 }
@@ -0,0 +1,92 @@
+//! Workspace-style metadata inheritance across a skill source directory
+//!
+//! A `KnownSources` directory holding many skills usually shares an
+//! author, license, homepage, and tag set across all of them, which
+//! otherwise has to be repeated in every `skill.json`/`kilo.yaml`/etc.
+//! [`WorkspaceManifest`] is an optional `alltheskills.toml` placed at the
+//! root of a source directory holding those shared defaults -- the way a
+//! Cargo workspace's `[workspace.package]` holds shared package metadata.
+//!
+//! A provider that scans a directory of skills loads the workspace
+//! manifest once per [`list_skills`](crate::providers::SkillProvider::list_skills)
+//! call (the first pass), then resolves each skill's [`Inheritable`]
+//! fields against it while parsing (the second pass). A manifest field
+//! opts into inheritance by setting `{ workspace = true }` instead of a
+//! literal value; [`Inheritable::resolve`] errors clearly if that marker
+//! has no corresponding workspace value.
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::{Error, Result};
+
+const WORKSPACE_MANIFEST_FILENAME: &str = "alltheskills.toml";
+
+/// Shared defaults for every skill under a source directory, loaded from
+/// `alltheskills.toml` at that directory's root
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct WorkspaceManifest {
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub homepage: Option<String>,
+    #[serde(default)]
+    pub repository: Option<String>,
+    #[serde(default)]
+    pub license: Option<String>,
+    #[serde(default)]
+    pub requirements: Vec<String>,
+}
+
+impl WorkspaceManifest {
+    /// Loads `alltheskills.toml` from `root`, or returns `None` if the
+    /// source directory doesn't declare one
+    pub fn load(root: &Path) -> Result<Option<Self>> {
+        let path = root.join(WORKSPACE_MANIFEST_FILENAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let manifest = toml::from_str(&content).map_err(|e| Error::Parse {
+            message: format!("invalid workspace manifest {}: {e}", path.display()),
+        })?;
+        Ok(Some(manifest))
+    }
+}
+
+/// A manifest field that either holds its value literally, or defers to
+/// the matching field on the source directory's [`WorkspaceManifest`] via
+/// `{ workspace = true }`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Inheritable<T> {
+    Value(T),
+    Workspace { workspace: bool },
+}
+
+impl<T: Default> Default for Inheritable<T> {
+    fn default() -> Self {
+        Inheritable::Value(T::default())
+    }
+}
+
+impl<T: Clone + Default> Inheritable<T> {
+    /// Resolves this field against `workspace_value` -- the matching field
+    /// read from the source directory's [`WorkspaceManifest`], if one was
+    /// loaded. `field` names the manifest field, used only in the error
+    /// message when `{ workspace = true }` has nothing to inherit.
+    pub fn resolve(self, workspace_value: Option<&T>, field: &str) -> Result<T> {
+        match self {
+            Inheritable::Value(v) => Ok(v),
+            Inheritable::Workspace { workspace: true } => workspace_value.cloned().ok_or_else(|| Error::Parse {
+                message: format!(
+                    "'{field}' sets {{ workspace = true }} but the workspace manifest has no '{field}' value"
+                ),
+            }),
+            Inheritable::Workspace { workspace: false } => Ok(T::default()),
+        }
+    }
+}
@@ -88,6 +88,12 @@ fn test_source_config_creation() {
         enabled: true,
         scope: alltheskills::types::SkillScope::User,
         priority: 0,
+        github: None,
+        path: None,
+        pattern: None,
+        registry: None,
+        custom: None,
+        oci: None,
     };
 
     assert_eq!(config.name, "test-source");
@@ -126,7 +132,14 @@ fn test_config_defaults() {
 
     assert_eq!(config.version, 1);
     assert!(matches!(config.default_scope, alltheskills::types::SkillScope::User));
-    assert!(config.sources.is_empty());
+    // A fresh config is enabled-by-default for every built-in provider
+    // except `github`, `registry` and `oci` (see `default_sources`'s doc
+    // comment), not empty.
+    assert_eq!(config.sources.len(), 11);
+    assert!(config.sources.iter().all(|s| s.enabled));
+    assert!(config.sources.iter().any(|s| s.name == "Claude Code"));
+    assert!(config.sources.iter().any(|s| s.name == "Local"));
+    assert!(!config.sources.iter().any(|s| s.source_type == SourceType::GitHub));
     assert_eq!(config.install_dir, std::path::PathBuf::from(".alltheskills"));
     assert_eq!(config.cache_dir, std::path::PathBuf::from(".alltheskills/cache"));
 }
@@ -120,7 +120,7 @@ fn test_cursor_provider_source_type() {
 fn test_can_handle_local_source() {
     let provider = LocalProvider;
     let source = SkillSource::Local {
-        path: PathBuf::from("/test/path"),
+        path: PathBuf::from("/test/path").into(),
     };
     assert!(provider.can_handle(&source));
 }
@@ -133,6 +133,9 @@ fn test_can_handle_github_source() {
         repo: "test".to_string(),
         subdir: None,
         branch: None,
+        version_req: None,
+        auth_token: None,
+        ssh: false,
     };
     assert!(provider.can_handle(&source));
 }
@@ -141,7 +144,7 @@ fn test_can_handle_github_source() {
 fn test_can_handle_roo_source() {
     let provider = RooProvider;
     let source = SkillSource::Local {
-        path: PathBuf::from("/home/user/.roo/skills/my-skill"),
+        path: PathBuf::from("/home/user/.roo/skills/my-skill").into(),
     };
     assert!(provider.can_handle(&source));
 }
@@ -150,7 +153,7 @@ fn test_can_handle_roo_source() {
 fn test_can_handle_cline_source() {
     let provider = ClineProvider;
     let source = SkillSource::Local {
-        path: PathBuf::from("/home/user/.cline/skills/my-skill"),
+        path: PathBuf::from("/home/user/.cline/skills/my-skill").into(),
     };
     assert!(provider.can_handle(&source));
 }
@@ -159,7 +162,7 @@ fn test_can_handle_cline_source() {
 fn test_can_handle_moltbot_source() {
     let provider = MoltbotProvider;
     let source = SkillSource::Local {
-        path: PathBuf::from("/home/user/.moltbot/skills/my-skill"),
+        path: PathBuf::from("/home/user/.moltbot/skills/my-skill").into(),
     };
     assert!(provider.can_handle(&source));
 }
@@ -168,7 +171,7 @@ fn test_can_handle_moltbot_source() {
 fn test_can_handle_clawdbot_legacy_source() {
     let provider = MoltbotProvider;
     let source = SkillSource::Local {
-        path: PathBuf::from("/home/user/.clawdbot/skills/my-skill"),
+        path: PathBuf::from("/home/user/.clawdbot/skills/my-skill").into(),
     };
     assert!(provider.can_handle(&source));
 }
@@ -177,7 +180,7 @@ fn test_can_handle_clawdbot_legacy_source() {
 fn test_can_handle_cursor_source() {
     let provider = CursorProvider;
     let source = SkillSource::Local {
-        path: PathBuf::from("/home/user/.cursor/rules/my-rules.cursorrules"),
+        path: PathBuf::from("/home/user/.cursor/rules/my-rules.cursorrules").into(),
     };
     assert!(provider.can_handle(&source));
 }
@@ -186,7 +189,7 @@ fn test_can_handle_cursor_source() {
 fn test_can_handle_cursor_project_source() {
     let provider = CursorProvider;
     let source = SkillSource::Local {
-        path: PathBuf::from("/my-project/.cursorrules"),
+        path: PathBuf::from("/my-project/.cursorrules").into(),
     };
     assert!(provider.can_handle(&source));
 }
@@ -10,10 +10,10 @@ fn test_skill_creation() {
         description: "A test skill".to_string(),
         version: Some("1.0.0".to_string()),
         source: SkillSource::Local {
-            path: PathBuf::from("/test"),
+            path: PathBuf::from("/test").into(),
         },
         source_type: SourceType::Local,
-        path: PathBuf::from("/test/skill"),
+        path: PathBuf::from("/test/skill").into(),
         installed_at: chrono::Utc::now(),
         metadata: SkillMetadata::default(),
         format: SkillFormat::GenericMarkdown,
@@ -79,14 +79,20 @@ fn test_skill_source_github() {
         repo: "test-repo".to_string(),
         subdir: Some("skills/my-skill".to_string()),
         branch: Some("main".to_string()),
+        version_req: Some("^1.0.0".to_string()),
+        auth_token: None,
+        ssh: false,
     };
 
     match source {
-        SkillSource::GitHub { owner, repo, subdir, branch } => {
+        SkillSource::GitHub { owner, repo, subdir, branch, version_req, auth_token, ssh } => {
             assert_eq!(owner, "test-owner");
             assert_eq!(repo, "test-repo");
             assert_eq!(subdir, Some("skills/my-skill".to_string()));
             assert_eq!(branch, Some("main".to_string()));
+            assert_eq!(version_req, Some("^1.0.0".to_string()));
+            assert_eq!(auth_token, None);
+            assert!(!ssh);
         }
         _ => panic!("Expected GitHub variant"),
     }